@@ -0,0 +1,46 @@
+harness::solution!(1, parse, part1, part2);
+
+pub fn parse(input: &str) -> Vec<f32> {
+    input.split_whitespace()
+        .map(|line| line.parse::<f32>().unwrap())
+        .collect()
+}
+
+pub fn part1(masses: &[f32]) -> i32 {
+    masses.iter().map(|&mass| calculate_fuel(mass)).sum()
+}
+
+pub fn part2(masses: &[f32]) -> i32 {
+    let mut fuel = 0;
+
+    for &mass in masses {
+        let fuel_of_module = calculate_fuel(mass);
+
+        fuel += fuel_of_module;
+
+        let mut last_fuel = fuel_of_module;
+
+        loop {
+            let fuel_of_fuel = calculate_fuel(last_fuel as f32);
+
+            if fuel_of_fuel <= 0 {
+                break;
+            }
+
+            fuel += fuel_of_fuel;
+            last_fuel = fuel_of_fuel;
+        }
+    }
+
+    fuel
+}
+
+fn calculate_fuel(mass: f32) -> i32 {
+    let mut fuel = mass / 3f32;
+
+    fuel = fuel.floor();
+
+    fuel -= 2f32;
+
+    fuel as i32
+}
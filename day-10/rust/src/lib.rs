@@ -0,0 +1,49 @@
+pub mod map;
+use map::{Asteroid, Map};
+
+harness::solution!(10, parse, part1, part2, |input: &str| harness::validate::grid(input, ".#"),
+    artifact: find_monitoring_station => part1_from_station, part2_from_station);
+
+pub fn parse(input: &str) -> Map {
+    Map::from_input(input.to_string())
+}
+
+pub fn part1(map: &Map) -> i64 {
+    part1_from_station(map, &find_monitoring_station(map))
+}
+
+pub fn part2(map: &Map) -> i64 {
+    part2_from_station(map, &find_monitoring_station(map))
+}
+
+pub fn part1_from_station(_map: &Map, station: &(Asteroid, i64)) -> i64 {
+    let (_, count) = station;
+
+    *count
+}
+
+pub fn part2_from_station(map: &Map, station: &(Asteroid, i64)) -> i64 {
+    let mut map = map.clone();
+
+    let (monitoring_station, _) = station;
+
+    // Remove the monitoring_station
+
+    map.remove(monitoring_station.clone());
+
+    let shot = map.calculate_nth_shot(monitoring_station.location.clone(), 200);
+
+    shot.location.x * 100 + shot.location.y
+}
+
+pub fn find_monitoring_station(map: &Map) -> (Asteroid, i64) {
+    let los = map.calculate_line_of_sight();
+
+    let mut los_vec: Vec<(&Asteroid, &i64)> = los.iter().collect();
+
+    los_vec.sort_by_key(|a| a.1);
+
+    let most_asteroids = los_vec.last().unwrap();
+
+    (most_asteroids.0.clone(), *most_asteroids.1)
+}
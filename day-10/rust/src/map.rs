@@ -1,6 +1,6 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[derive(Debug, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
 pub struct Point {
     pub x: i64,
     pub y: i64
@@ -10,6 +10,12 @@ pub struct Uniques<T> {
     angles: Vec<T>
 }
 
+impl<T: std::cmp::PartialEq> Default for Uniques<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T: std::cmp::PartialEq> Uniques<T> {
     pub fn new() -> Uniques<T> {
         Uniques {
@@ -21,6 +27,10 @@ impl<T: std::cmp::PartialEq> Uniques<T> {
         self.angles.len()
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.angles.is_empty()
+    }
+
     pub fn add(&mut self, other: T) {
         if self.angles.contains(&other) {
             return;
@@ -30,12 +40,12 @@ impl<T: std::cmp::PartialEq> Uniques<T> {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Asteroid {
     pub location: Point
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Map {
     coordinates: Vec<Vec<Option<Asteroid>>>,
     asteroids: Vec<Asteroid>
@@ -126,12 +136,12 @@ impl Map {
     }
 
     pub fn remove(&mut self, ast: Asteroid) {
-        self.asteroids.remove_item(&ast);
+        self.asteroids.retain(|a| *a != ast);
         self.coordinates[ast.location.y as usize][ast.location.x as usize] = None;
     }
 
-    pub fn calculate_line_of_sight(&mut self) -> HashMap<Asteroid, i64> {
-        let mut ast_counts: HashMap<Asteroid, i64> = HashMap::new();
+    pub fn calculate_line_of_sight(&self) -> BTreeMap<Asteroid, i64> {
+        let mut ast_counts: BTreeMap<Asteroid, i64> = BTreeMap::new();
 
         for asteroid in &self.asteroids {
             let origin = asteroid.location.clone();
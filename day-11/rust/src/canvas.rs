@@ -1,6 +1,6 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet};
 
-#[derive(Debug, Eq, Hash, PartialEq, Clone, Copy)]
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Clone, Copy)]
 pub struct Location {
     pub x: i64,
     pub y: i64
@@ -23,15 +23,21 @@ pub enum Colour {
 
 #[derive(Debug)]
 pub struct Canvas {
-    pub painted: HashMap<Location, Colour>,
-    pub history: HashSet<Location>
+    pub painted: BTreeMap<Location, Colour>,
+    pub history: BTreeSet<Location>
+}
+
+impl Default for Canvas {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Canvas {
     pub fn new() -> Canvas {
         Canvas {
-            painted: HashMap::new(),
-            history: HashSet::new()
+            painted: BTreeMap::new(),
+            history: BTreeSet::new()
         }
     }
 
@@ -41,6 +47,6 @@ impl Canvas {
 
     pub fn set_colour(&mut self, location: &Location, colour: Colour) {
         self.history.insert(*location);
-        self.painted.insert(location.clone(), colour);
+        self.painted.insert(*location, colour);
     }
 }
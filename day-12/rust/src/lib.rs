@@ -0,0 +1,125 @@
+use std::thread;
+
+pub mod moon;
+
+use moon::{Moon, Vector3};
+
+harness::solution!(12, parse, part1, part2);
+harness::engine!(12, 2, "serial", parse, part2_serial);
+
+fn gcd(x: i64, y: i64) -> i64 {
+    if x == 0 {
+        y
+    } else {
+        gcd(y % x, x)
+    }
+}
+
+fn lcm(x: i64, y: i64) -> i64 {
+    if x == 0 || y == 0 {
+        0
+    } else {
+        (x * y) / gcd(x, y)
+    }
+}
+
+pub fn parse(input: &str) -> Vec<Moon> {
+    input.split_terminator('\n').map(|x| Moon::parse(x.to_string())).collect::<Vec<Moon>>()
+}
+
+pub fn part1(moons: &[Moon]) -> i64 {
+    let mut simulated = moons.to_vec();
+
+    for _ in 0..1000 {
+        step(&mut simulated);
+    }
+
+    let mut energy = 0;
+
+    for moon in &simulated {
+        let pot = moon.location.x.abs() + moon.location.y.abs() + moon.location.z.abs();
+        let kin = moon.velocity.x.abs() + moon.velocity.y.abs() + moon.velocity.z.abs();
+        energy += pot * kin;
+    }
+
+    energy
+}
+
+pub fn part2(moons: &[Moon]) -> i64 {
+    let periods = find_periods_parallel(axes_of(moons));
+
+    lcm(lcm(periods[0], periods[1]), periods[2])
+}
+
+pub fn part2_serial(moons: &[Moon]) -> i64 {
+    let periods = find_periods_serial(axes_of(moons));
+
+    lcm(lcm(periods[0], periods[1]), periods[2])
+}
+
+fn axes_of(moons: &[Moon]) -> Vec<Vec<(i64, i64)>> {
+    vec![
+        moons.iter().map(|m| (m.location.x, m.velocity.x)).collect::<Vec<_>>(),
+        moons.iter().map(|m| (m.location.y, m.velocity.y)).collect::<Vec<_>>(),
+        moons.iter().map(|m| (m.location.z, m.velocity.z)).collect::<Vec<_>>()
+    ]
+}
+
+fn step(moons: &mut [Moon]) {
+    for moon1 in 0..moons.len() {
+        for moon2 in 0..moons.len() {
+            if moon1 == moon2 {
+                continue;
+            }
+
+            let (a, b) = (moons[moon1].location, moons[moon2].location);
+
+            moons[moon1].velocity += Vector3 {
+                x: (b.x - a.x).signum(),
+                y: (b.y - a.y).signum(),
+                z: (b.z - a.z).signum()
+            };
+        }
+    }
+
+    for moon in moons {
+        moon.apply_velocity();
+    }
+}
+
+fn find_periods_serial(axes: Vec<Vec<(i64, i64)>>) -> Vec<i64> {
+    axes.into_iter().map(find_period).collect()
+}
+
+fn find_periods_parallel(axes: Vec<Vec<(i64, i64)>>) -> Vec<i64> {
+    thread::scope(|s| {
+        let handles = axes.into_iter()
+            .map(|axis| s.spawn(move || find_period(axis)))
+            .collect::<Vec<_>>();
+
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    })
+}
+
+fn find_period(initial: Vec<(i64, i64)>) -> i64 {
+    let mut state = initial.clone();
+    let mut steps = 0;
+
+    loop {
+        for i in 0..state.len() {
+            for j in 0..state.len() {
+                state[i].1 += (state[j].0 - state[i].0).signum();
+            }
+        }
+
+        for (position, velocity) in &mut state {
+            *position += *velocity;
+        }
+
+        steps += 1;
+
+        if state == initial {
+            return steps;
+        }
+    }
+}
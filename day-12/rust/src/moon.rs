@@ -62,6 +62,6 @@ impl Moon {
     }
 
     pub fn apply_velocity(&mut self) {
-        self.location = self.location + self.velocity;
+        self.location += self.velocity;
     }
 }
@@ -1,4 +1,6 @@
 use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
 
 #[derive(Debug, PartialEq, Clone)]
 pub enum Tile {
@@ -15,10 +17,20 @@ pub struct Point {
     pub y: i64
 }
 
+#[derive(Debug, PartialEq, Clone)]
+pub enum Event {
+    Score(i64),
+    Tile(Point, Tile)
+}
+
 #[derive(Debug)]
+pub struct EventParser {
+    pending: Vec<i64>
+}
+
+#[derive(Debug, Clone)]
 pub struct Area {
-    grid: HashMap<Point, Tile>,
-    score_board: i64,
+    grid: Arc<HashMap<Point, Tile>>,
     ball_pos: Point,
     paddle_pos: Point
 }
@@ -34,13 +46,60 @@ impl Tile {
             _ => panic!("Unimplemented tile type: {}", i)
         }
     }
+
+    pub fn glyph(&self) -> char {
+        match self {
+            Tile::Empty => ' ',
+            Tile::Wall => '#',
+            Tile::Block => '=',
+            Tile::HorizontalPaddle => '_',
+            Tile::Ball => 'o'
+        }
+    }
+}
+
+impl Default for EventParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventParser {
+    pub fn new() -> EventParser {
+        EventParser {
+            pending: Vec::new()
+        }
+    }
+
+    pub fn push(&mut self, output: i64) -> Option<Event> {
+        self.pending.push(output);
+
+        if self.pending.len() < 3 {
+            return None;
+        }
+
+        let (x, y, t) = (self.pending[0], self.pending[1], self.pending[2]);
+
+        self.pending.clear();
+
+        if x == -1 && y == 0 {
+            Some(Event::Score(t))
+        } else {
+            Some(Event::Tile(Point { x, y }, Tile::from_int(t)))
+        }
+    }
+}
+
+impl Default for Area {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Area {
     pub fn new() -> Area {
         Area {
-            grid: HashMap::new(),
-            score_board: 0,
+            grid: Arc::new(HashMap::new()),
             ball_pos: Point { x: 0, y: 0 },
             paddle_pos: Point { x: 0, y: 0 }
         }
@@ -56,10 +115,6 @@ impl Area {
         }
     }
 
-    pub fn set_score(&mut self, score: i64) {
-        self.score_board = score;
-    }
-
     pub fn set(&mut self, point: Point, tile: Tile) {
         if tile == Tile::Ball {
             self.ball_pos = point.clone();
@@ -69,16 +124,96 @@ impl Area {
             self.paddle_pos = point.clone();
         }
 
-        self.grid.insert(point, tile);
+        Arc::make_mut(&mut self.grid).insert(point, tile);
     }
 
     pub fn find_count_of(&self, tile: Tile) -> i64 {
-        let mut data: Vec<_> = self.grid.iter().collect();
+        self.grid.values().filter(|&t| *t == tile).count() as i64
+    }
+
+    // Draws the screen followed by a score line. Writing into any fmt::Write
+    // sink keeps the output plain so it can be captured and compared
+    pub fn render<W: fmt::Write>(&self, score: i64, out: &mut W) -> fmt::Result {
+        if !self.grid.is_empty() {
+            let min_x = self.grid.keys().map(|p| p.x).min().unwrap();
+            let max_x = self.grid.keys().map(|p| p.x).max().unwrap();
+            let min_y = self.grid.keys().map(|p| p.y).min().unwrap();
+            let max_y = self.grid.keys().map(|p| p.y).max().unwrap();
+
+            for y in min_y..=max_y {
+                for x in min_x..=max_x {
+                    let glyph = self.grid.get(&Point { x, y }).map_or(' ', Tile::glyph);
+
+                    out.write_char(glyph)?;
+                }
+
+                out.write_char('\n')?;
+            }
+        }
+
+        writeln!(out, "Score: {}", score)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        data.drain_filter(|x| *x.1 == tile).collect::<Vec<_>>().len() as i64
+    // Feeds raw machine output through the parser the way the game loop does
+    fn screen(outputs: &[i64]) -> String {
+        let mut area = Area::new();
+        let mut events = EventParser::new();
+        let mut score = 0;
+
+        for &value in outputs {
+            match events.push(value) {
+                Some(Event::Score(s)) => score = s,
+                Some(Event::Tile(p, t)) => area.set(p, t),
+                None => {}
+            }
+        }
+
+        let mut rendered = String::new();
+        area.render(score, &mut rendered).unwrap();
+
+        rendered
     }
 
-    pub fn print_score(&self) {
-        println!("Part 2: {}", self.score_board);
+    #[test]
+    fn renders_an_empty_screen_as_only_the_score() {
+        assert_eq!(screen(&[]), "Score: 0\n");
+    }
+
+    #[test]
+    fn renders_every_tile_with_its_glyph() {
+        let outputs = [
+            0, 0, 1,  1, 0, 1,  2, 0, 1,  3, 0, 1,  4, 0, 1,
+            0, 1, 1,  1, 1, 2,  2, 1, 0,  3, 1, 2,  4, 1, 1,
+            0, 2, 1,  1, 2, 0,  2, 2, 4,  3, 2, 0,  4, 2, 1,
+            0, 3, 1,  1, 3, 0,  2, 3, 3,  3, 3, 0,  4, 3, 1,
+            -1, 0, 1234
+        ];
+
+        assert_eq!(screen(&outputs), "#####\n#= =#\n# o #\n# _ #\nScore: 1234\n");
+    }
+
+    #[test]
+    fn leaves_gaps_blank_and_keeps_the_latest_tile() {
+        let outputs = [
+            0, 0, 1,  3, 0, 1,
+            1, 1, 4,
+            -1, 0, 7,
+            1, 1, 0,  2, 1, 4,
+            -1, 0, 12
+        ];
+
+        assert_eq!(screen(&outputs), "#  #\n  o \nScore: 12\n");
+    }
+
+    #[test]
+    fn renders_tiles_left_and_above_the_origin() {
+        let outputs = [-2, -1, 3, 0, 0, 2];
+
+        assert_eq!(screen(&outputs), "_  \n  =\nScore: 0\n");
     }
 }
@@ -0,0 +1,114 @@
+pub mod game;
+
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::Arc;
+
+use game::*;
+use intcode::FnInput;
+pub use intcode::parse;
+
+harness::solution!(13, parse, part1, part2, harness::validate::intcode_program);
+harness::engine!(13, 2, "fast", parse, part2_fast);
+harness::engine!(13, 2, "viz", parse, part2_viz);
+
+pub fn part1(codes: &[i64]) -> i64 {
+    let mut game_area = Area::new();
+    let mut events = EventParser::new();
+
+    let mut interpreter = intcode::Interpreter::new(codes.to_vec(), vec![]);
+
+    while let Some(value) = next_output(&mut interpreter) {
+        if let Some(Event::Tile(p, t)) = events.push(value) {
+            game_area.set(p, t);
+        }
+    }
+
+    game_area.find_count_of(Tile::Block)
+}
+
+pub fn part2(codes: &[i64]) -> i64 {
+    play(codes, |_, _| {})
+}
+
+// Redraws the screen every time the ball moves
+pub fn part2_viz(codes: &[i64]) -> i64 {
+    play(codes, |game_area, score| {
+        let mut frame = String::from("\x1b[H\x1b[2J");
+
+        game_area.render(score, &mut frame).unwrap();
+
+        print!("{}", frame);
+    })
+}
+
+fn play<F: FnMut(&Area, i64)>(codes: &[i64], mut on_ball_moved: F) -> i64 {
+    let mut codes = codes.to_vec();
+
+    let mut game_area = Area::new();
+    let mut events = EventParser::new();
+    let mut score = 0;
+
+    codes[0] = 2;
+
+    // The joystick follows the ball, the machine reads wherever it was left
+    let joystick = Arc::new(AtomicI64::new(0));
+    let held = Arc::clone(&joystick);
+
+    let mut interpreter = intcode::Interpreter::new(codes.to_vec(), vec![]);
+    interpreter.set_input(FnInput(move || Some(held.load(Ordering::Relaxed))));
+
+    while let Some(value) = next_output(&mut interpreter) {
+        match events.push(value) {
+            Some(Event::Score(s)) => score = s,
+            Some(Event::Tile(p, t)) => {
+                let is_ball = t == Tile::Ball;
+
+                game_area.set(p, t);
+
+                if is_ball {
+                    on_ball_moved(&game_area, score);
+                }
+            },
+            None => {}
+        }
+
+        joystick.store(game_area.get_joystick(), Ordering::Relaxed);
+    }
+
+    score
+}
+
+// Skips the screen entirely and only follows the ball, the paddle and the
+// score, which is all the joystick and the answer depend on
+pub fn part2_fast(codes: &[i64]) -> i64 {
+    let mut codes = codes.to_vec();
+
+    let mut events = EventParser::new();
+    let (mut ball_x, mut paddle_x, mut score) = (0i64, 0i64, 0);
+
+    codes[0] = 2;
+
+    let joystick = Arc::new(AtomicI64::new(0));
+    let held = Arc::clone(&joystick);
+
+    let mut interpreter = intcode::Interpreter::new(codes, vec![]);
+    interpreter.set_input(FnInput(move || Some(held.load(Ordering::Relaxed))));
+
+    while let Some(value) = next_output(&mut interpreter) {
+        match events.push(value) {
+            Some(Event::Score(s)) => score = s,
+            Some(Event::Tile(p, Tile::Ball)) => ball_x = p.x,
+            Some(Event::Tile(p, Tile::HorizontalPaddle)) => paddle_x = p.x,
+            _ => {}
+        }
+
+        joystick.store((ball_x - paddle_x).signum(), Ordering::Relaxed);
+    }
+
+    score
+}
+
+// The next value the game prints, `None` once it has stopped
+fn next_output(interpreter: &mut intcode::Interpreter) -> Option<i64> {
+    interpreter.run_until_output().unwrap_or_else(|trap| panic!("Game trapped: {}", trap))
+}
@@ -1,13 +1,13 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
 pub struct Chemical {
     pub name: String,
 }
 
 #[derive(Debug, Clone)]
 pub struct Recipe {
-    pub inputs: HashMap<Chemical, i64>,
+    pub inputs: BTreeMap<Chemical, i64>,
     pub output: (i64, Chemical)
 }
 
@@ -29,11 +29,11 @@ impl Recipe {
             (Chemical {
                 name: chem[1].to_string(),
             }, chem[0].parse::<i64>().unwrap())
-        }).collect::<HashMap<Chemical, i64>>();
+        }).collect::<BTreeMap<Chemical, i64>>();
 
         Recipe {
-            inputs: inputs,
-            output: output
+            inputs,
+            output
         }
     }
 }
@@ -0,0 +1,29 @@
+pub use intcode::parse;
+
+harness::solution!(2, parse, part1, part2, harness::validate::intcode_program);
+
+const TO_FIND: i64 = 19690720;
+
+pub fn part1(codes: &[i64]) -> i64 {
+    // Account for Error
+    run_with(codes, 12, 2)
+}
+
+pub fn part2(codes: &[i64]) -> i64 {
+    // Noun and verb are both 0..=99, so 100 * noun + verb enumerates every pair
+    harness::search::parallel_find(0..10000, 100, |i| run_with(codes, i / 100, i % 100) == TO_FIND)
+        .unwrap_or_else(|| panic!("No noun and verb produce {}", TO_FIND))
+}
+
+fn run_with(codes: &[i64], noun: i64, verb: i64) -> i64 {
+    let mut codes = codes.to_vec();
+
+    codes[1] = noun;
+    codes[2] = verb;
+
+    let mut interpreter = intcode::Interpreter::with_level(codes, vec![], intcode::Level::Day2);
+
+    interpreter.run().unwrap_or_else(|e| panic!("{}", e));
+
+    interpreter.fetch(0)
+}
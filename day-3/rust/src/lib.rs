@@ -0,0 +1,74 @@
+pub mod wire;
+pub mod parser;
+
+use std::collections::HashSet;
+use wire::{Wire, Point};
+
+harness::solution!(3, parse, part1, part2);
+
+pub fn parse(input: &str) -> Vec<Wire> {
+    input.split_whitespace().map(parser::parse).collect::<Vec<Wire>>()
+}
+
+pub fn part1(wires: &[Wire]) -> i64 {
+    let sets = wires.iter().map(|wire| wire.clone().to_set()).collect::<Vec<HashSet<Point>>>();
+
+    let a = &sets[0];
+    let b = &sets[1];
+    let origin = Point::new(0, 0);
+
+    let manhattan_intersection = a.intersection(b)
+        .map(|p| (p, p.manhattan_distance(&origin)))
+        .collect::<Vec<(&Point, i64)>>();
+
+    let mut smallest: (&Point, i64) = manhattan_intersection[1];
+
+    for (p, dist) in manhattan_intersection {
+        if p.x == 0 && p.y == 0 {
+            continue;
+        }
+
+        if dist < smallest.1 {
+            smallest = (p, dist);
+        }
+    }
+
+    smallest.1
+}
+
+pub fn part2(wires: &[Wire]) -> i64 {
+    let sets = wires.iter().map(|wire| wire.clone().to_set()).collect::<Vec<HashSet<Point>>>();
+
+    let a = &sets[0];
+    let b = &sets[1];
+    let origin = Point::new(0, 0);
+
+    let smallest_steps_intersection = a.intersection(b)
+        .collect::<Vec<&Point>>();
+
+    let mut first = true;
+    let mut smallest: (&Point, i64) = (&origin, 0);
+
+    for point in smallest_steps_intersection {
+        if point.x == 0 && point.y == 0 {
+            continue;
+        }
+
+        let mut combined = 0;
+
+        for wire in wires {
+            combined += wire.clone().find_steps_to(point) + 1;
+        }
+
+        if first {
+            smallest = (point, combined);
+            first = false;
+        } else {
+            if combined < smallest.1 {
+                smallest = (point, combined);
+            }
+        }
+    }
+
+    smallest.1
+}
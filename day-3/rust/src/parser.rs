@@ -10,7 +10,7 @@ pub fn parse(instructions: &str) -> Wire {
     for instruction in inst {
         let dir = &instruction[..1];
         let dist = &instruction[1..].parse::<i64>()
-          .expect(&format!("Could not parse integer of {}", instruction));
+          .unwrap_or_else(|_| panic!("Could not parse integer of {}", instruction));
 
         for _ in 0..*dist {
             match dir {
@@ -0,0 +1,117 @@
+use std::ops::Range;
+
+harness::solution!(4, parse, part1, part2);
+
+pub fn parse(input: &str) -> Range<i32> {
+    let split = input.split("-");
+
+    let parsed = split.map(|splt| splt.trim().parse::<i32>().unwrap()).collect::<Vec<i32>>();
+
+    let (lower, upper) = (parsed[0], parsed[1]);
+
+    lower..upper
+}
+
+pub fn part1(range: &Range<i32>) -> usize {
+    let range = range.start as i64..range.end as i64;
+
+    harness::search::parallel_count(range, 10000, |i| number_meets_part_one(i as i32))
+}
+
+pub fn part2(range: &Range<i32>) -> usize {
+    let range = range.start as i64..range.end as i64;
+
+    harness::search::parallel_count(range, 10000, |i| number_meets_part_two(i as i32))
+}
+
+fn number_meets_part_one(number: i32) -> bool {
+    if not_decreasing(number)
+        && has_double_integer(number) {
+            return true;
+        }
+
+    false
+}
+
+fn number_meets_part_two(number: i32) -> bool {
+    if not_decreasing(number)
+        && has_no_larger_group(number) {
+            return true;
+        }
+
+    false
+}
+
+fn has_double_integer(number: i32) -> bool {
+    let digits = get_digits(number);
+
+    let rle = rle(digits);
+
+    let groups = rle.iter().map(|x| x.0 > 1).collect::<Vec<bool>>();
+
+    if groups.contains(&true) {
+        return true;
+    }
+
+    false
+}
+
+fn rle(list: Vec<i32>) -> Vec<(i32, i32)> {
+    let mut rle: Vec<(i32, i32)> = Vec::new();
+
+    for digit in list.iter() {
+        if let Some(x) = rle.last() {
+            if x.1 == *digit {
+                let len = rle.len();
+                rle[len - 1] = (x.0 + 1, *digit);
+            } else {
+                rle.push((1, *digit));
+            }
+        } else {
+            rle.push((1, *digit));
+        }
+    }
+
+    rle
+}
+
+fn has_no_larger_group(number: i32) -> bool {
+    let digits = get_digits(number);
+
+    let rle = rle(digits);
+
+    let groups = rle.iter().map(|x| x.0).collect::<Vec<i32>>();
+
+    if groups.contains(&2) {
+        return true;
+    }
+
+    false
+}
+
+pub fn get_digits(number: i32) -> Vec<i32> {
+    number.to_string()
+        .chars()
+        .filter_map(|x| x.to_digit(10))
+        .map(|x| x as i32)
+        .collect()
+}
+
+fn not_decreasing(number: i32) -> bool {
+    let string = number.to_string();
+
+    let mut last_number = 0;
+
+    for character in string.chars() {
+        let digit = character.to_digit(10).unwrap();
+
+
+        if digit < last_number {
+            return false;
+        }
+
+        last_number = digit;
+    }
+
+    true
+}
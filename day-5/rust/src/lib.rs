@@ -0,0 +1,29 @@
+pub use intcode::parse;
+
+harness::solution!(5, parse, part1, part2, harness::validate::intcode_program);
+
+pub fn part1(codes: &[i64]) -> i64 {
+    diagnostic_code(&run_diagnostics(codes, 1))
+}
+
+pub fn part2(codes: &[i64]) -> i64 {
+    diagnostic_code(&run_diagnostics(codes, 5))
+}
+
+fn run_diagnostics(codes: &[i64], system_id: i64) -> Vec<i64> {
+    let mut interpreter = intcode::Interpreter::with_level(codes.to_vec(), vec![], intcode::Level::Day5);
+
+    interpreter.push_input(system_id);
+
+    interpreter.run().unwrap_or_else(|e| panic!("{}", e))
+}
+
+fn diagnostic_code(outputs: &[i64]) -> i64 {
+    let (code, tests) = outputs.split_last().expect("Program produced no output");
+
+    if let Some(i) = tests.iter().position(|&t| t != 0) {
+        panic!("Diagnostic test {} failed with output {}", i + 1, tests[i]);
+    }
+
+    *code
+}
@@ -0,0 +1,89 @@
+use itertools::Itertools;
+use intcode::{Level, MachinePool};
+pub use intcode::parse;
+
+harness::solution!(7, parse, part1, part2, harness::validate::intcode_program);
+
+pub fn part1(codes: &[i64]) -> i64 {
+    let mut pool = MachinePool::with_level(codes.to_vec(), Level::Day5);
+    let mut biggest = 0;
+
+    for comb in (0..=4).permutations(5) {
+        let signal = run_combination_part_1(comb, &mut pool);
+
+        if signal > biggest {
+            biggest = signal;
+        }
+    }
+
+    biggest
+}
+
+pub fn part2(codes: &[i64]) -> i64 {
+    let mut pool = MachinePool::with_level(codes.to_vec(), Level::Day5);
+    let mut biggest = 0;
+
+    for comb in (0..=9).permutations(5) {
+        let signal = run_combination_part_2(comb, &mut pool);
+
+        if signal > biggest {
+            biggest = signal;
+        }
+    }
+
+    biggest
+}
+
+fn run_combination_part_1(settings: Vec<i64>, pool: &mut MachinePool) -> i64 {
+    let mut last_output = 0;
+
+    for setting in settings {
+        let mut software = pool.acquire();
+
+        software.extend_inputs([setting, last_output]);
+
+        software.run().unwrap_or_else(|e| panic!("{}", e));
+
+        last_output = software.last_output;
+
+        pool.release(software);
+    };
+
+    last_output
+}
+
+fn run_combination_part_2(settings: Vec<i64>, pool: &mut MachinePool) -> i64 {
+    let mut last_output = 0;
+
+    let mut amplifiers: Vec<intcode::Interpreter> = vec![];
+
+    for setting in settings {
+        let mut amp = pool.acquire();
+        amp.push_input(setting);
+        amplifiers.push(amp);
+    }
+
+    let mut index = 0;
+
+    'outer: loop {
+        let amplifier = &mut amplifiers[index % 5];
+
+        amplifier.push_input(last_output);
+
+        last_output = match amplifier.run_until_output() {
+            Ok(Some(signal)) => signal,
+            Ok(None) => break 'outer,
+            Err(trap) => panic!("Amplifier trapped: {}", trap)
+        };
+
+        index += 1;
+    }
+
+    let signal = amplifiers.last().unwrap().last_output;
+
+    for amp in amplifiers {
+        pool.release(amp);
+    }
+
+    signal
+}
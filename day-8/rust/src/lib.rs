@@ -0,0 +1,71 @@
+pub mod letters;
+
+harness::solution!(8, parse, part1, part2, harness::validate::digits);
+
+const IMAGE_WIDTH: usize = 25;
+const IMAGE_HEIGHT: usize = 6;
+
+pub fn parse(input: &str) -> Vec<i32> {
+    input.trim_end().chars().map(|c| c.to_digit(10).unwrap() as i32).collect::<Vec<i32>>()
+}
+
+fn split_layers(input: &[i32]) -> Vec<Vec<i32>> {
+    let pixels_per_layer = IMAGE_WIDTH * IMAGE_HEIGHT;
+
+    input.chunks(pixels_per_layer).map(Vec::from).collect()
+}
+
+pub fn part1(input: &[i32]) -> usize {
+    let layer_data = split_layers(input);
+
+    let mut sorted = layer_data.clone();
+
+    sorted.sort_by_key(|s| s.iter().filter(|&n| *n == 0).count());
+
+    let fewest_zeros = sorted.first().unwrap();
+
+    let num_1s = fewest_zeros.iter().filter(|&n| *n == 1).count();
+    let num_2s = fewest_zeros.iter().filter(|&n| *n == 2).count();
+
+    num_1s * num_2s
+}
+
+pub fn part2(input: &[i32]) -> String {
+    let layer_data = split_layers(input);
+
+    let mut layers: Vec<Vec<Vec<i32>>> = Vec::new();
+
+    for layer in layer_data {
+        let rows: Vec<Vec<i32>> = layer.chunks(IMAGE_WIDTH).map(Vec::from).collect();
+        layers.push(rows);
+    }
+
+    let mut constructed_image: Vec<Vec<i32>> = Vec::new();
+
+    for x in 0..IMAGE_HEIGHT {
+        constructed_image.push(Vec::new());
+        for y in 0..IMAGE_WIDTH {
+            for layer in &layers {
+                if layer[x][y] != 2 {
+                    constructed_image[x].push(layer[x][y]);
+                    break;
+                }
+            }
+        }
+    }
+
+    let mut columns: Vec<Vec<bool>> = Vec::new();
+
+    for y in 0..IMAGE_WIDTH {
+        let col: Vec<bool> = constructed_image.iter().map(|row| row[y] == 1).collect();
+        columns.push(col);
+    }
+
+    let mut solution = String::new();
+
+    for letter in columns.chunks(5) {
+        solution.push(letters::find_letter(letter.to_vec()));
+    }
+
+    solution
+}
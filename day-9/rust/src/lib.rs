@@ -0,0 +1,19 @@
+pub use intcode::parse;
+
+harness::solution!(9, parse, part1, part2, harness::validate::intcode_program);
+
+pub fn part1(codes: &[i64]) -> i64 {
+    boost(codes, 1)
+}
+
+pub fn part2(codes: &[i64]) -> i64 {
+    boost(codes, 2)
+}
+
+fn boost(codes: &[i64], mode: i64) -> i64 {
+    let outputs = intcode::Interpreter::new(codes.to_vec(), vec![])
+        .run_with_input(&[mode])
+        .unwrap_or_else(|e| panic!("{}", e));
+
+    *outputs.last().expect("BOOST produced no output")
+}
@@ -0,0 +1,52 @@
+use std::env;
+use std::process;
+
+use crate::{Input, Part};
+
+const USAGE: &str = "Options:
+    --input PATH     read the input from PATH instead of ../input, - reads stdin
+    --part N         only run part 1 or 2
+    --viz            show a visualization where the day has one, same as --engine viz
+    --engine NAME    pick an alternative implementation where the day has one";
+
+// The options every day binary understands
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Args {
+    pub input: Option<Input>,
+    pub part: Option<Part>,
+    pub viz: bool,
+    pub engine: Option<String>
+}
+
+impl Args {
+    pub fn parse_from<I: IntoIterator<Item = String>>(args: I) -> Result<Args, String> {
+        let mut parsed = Args::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or_else(|| format!("{} needs a value", arg));
+
+            match arg.as_str() {
+                "--input" => parsed.input = Some(Input::from_arg(&value()?)),
+                "--part" => parsed.part = Some(value()?.parse().map_err(|e| format!("--part: {}", e))?),
+                "--viz" => parsed.viz = true,
+                "--engine" => parsed.engine = Some(value()?),
+                _ => return Err(format!("Unknown argument {}", arg))
+            }
+        }
+
+        Ok(parsed)
+    }
+
+    pub fn runs_part(&self, part: Part) -> bool {
+        self.part.is_none_or(|p| p == part)
+    }
+}
+
+// Parses the process arguments, exiting with usage on anything unexpected
+pub fn args() -> Args {
+    Args::parse_from(env::args().skip(1)).unwrap_or_else(|e| {
+        eprintln!("{}\n\n{}", e, USAGE);
+        process::exit(2);
+    })
+}
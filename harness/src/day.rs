@@ -0,0 +1,147 @@
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+// A puzzle day, 1 to 25. Checked when it's made, so anything holding one can
+// rely on it being in range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Day(u8);
+
+impl Day {
+    // For days written into the code, where a bad one is a bug. In a const
+    // context, like a registration, it fails the build.
+    pub const fn new(day: u8) -> Day {
+        assert!(day >= 1 && day <= 25, "Day must be between 1 and 25");
+
+        Day(day)
+    }
+
+    pub fn all() -> impl Iterator<Item = Day> {
+        (1..=25).map(Day)
+    }
+
+    pub const fn get(self) -> u8 {
+        self.0
+    }
+}
+
+impl TryFrom<u8> for Day {
+    type Error = String;
+
+    fn try_from(day: u8) -> Result<Day, String> {
+        if (1..=25).contains(&day) {
+            Ok(Day(day))
+        } else {
+            Err(format!("Day must be between 1 and 25, got {}", day))
+        }
+    }
+}
+
+impl FromStr for Day {
+    type Err = String;
+
+    fn from_str(day: &str) -> Result<Day, String> {
+        let number = day.parse::<u8>().map_err(|_| format!("Day must be between 1 and 25, got {}", day))?;
+
+        Day::try_from(number)
+    }
+}
+
+impl fmt::Display for Day {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    // Like `Day::new`, for parts written into the code
+    pub const fn new(part: u8) -> Part {
+        match part {
+            1 => Part::One,
+            2 => Part::Two,
+            _ => panic!("Part must be 1 or 2")
+        }
+    }
+
+    pub const fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2
+        }
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = String;
+
+    fn try_from(part: u8) -> Result<Part, String> {
+        match part {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(format!("Part must be 1 or 2, got {}", part))
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(part: &str) -> Result<Part, String> {
+        match part {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("Part must be 1 or 2, got {}", part))
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn days_are_1_to_25() {
+        assert_eq!(Day::try_from(1).map(Day::get), Ok(1));
+        assert_eq!(Day::try_from(25).map(Day::get), Ok(25));
+        assert_eq!(Day::try_from(0), Err("Day must be between 1 and 25, got 0".to_string()));
+        assert_eq!(Day::try_from(26), Err("Day must be between 1 and 25, got 26".to_string()));
+        assert_eq!(Day::all().count(), 25);
+    }
+
+    #[test]
+    fn parses_days() {
+        assert_eq!("7".parse::<Day>(), Ok(Day::new(7)));
+        assert_eq!("26".parse::<Day>(), Err("Day must be between 1 and 25, got 26".to_string()));
+        assert_eq!("-1".parse::<Day>(), Err("Day must be between 1 and 25, got -1".to_string()));
+        assert_eq!(Day::new(7).to_string(), "7");
+    }
+
+    #[test]
+    fn parts_are_1_or_2() {
+        assert_eq!(Part::try_from(2), Ok(Part::Two));
+        assert_eq!(Part::try_from(3), Err("Part must be 1 or 2, got 3".to_string()));
+        assert_eq!("1".parse::<Part>(), Ok(Part::One));
+        assert_eq!("one".parse::<Part>(), Err("Part must be 1 or 2, got one".to_string()));
+        assert_eq!(Part::Two.to_string(), "2");
+    }
+
+    #[test]
+    #[should_panic(expected = "Day must be between 1 and 25")]
+    fn a_day_out_of_range_in_code_is_a_bug() {
+        Day::new(0);
+    }
+}
@@ -0,0 +1,128 @@
+use std::panic;
+use std::process;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+mod args;
+mod day;
+mod input;
+pub mod search;
+mod solution;
+pub mod validate;
+
+pub use args::{args, Args};
+pub use day::{Day, Part};
+pub use input::Input;
+pub use inventory;
+pub use solution::{engines, find, find_engine, solutions, with_artifact, Artifact, Engine, Registration, Solution};
+
+const INPUT_PATH: &str = "../input";
+
+// The engine `--viz` stands for
+const VIZ_ENGINE: &str = "viz";
+
+// 0 while reading the input, otherwise the part currently being solved
+static STAGE: AtomicU8 = AtomicU8::new(0);
+
+// The body of every day binary: solves the parts asked for with the day's
+// registered solution, or with the engine picked by `--engine` or `--viz`
+pub fn run(solution: &dyn Solution) {
+    let args = args();
+    let day = solution.day();
+
+    if let Some(name) = &args.engine {
+        if let Err(e) = check_engine(day, name) {
+            eprintln!("{}", e);
+            process::exit(2);
+        }
+    }
+
+    let source = args.input.clone().unwrap_or_else(|| Input::File(INPUT_PATH.into()));
+
+    install_panic_hook(day, source.to_string());
+
+    let input = source.read().unwrap_or_else(|e| {
+        eprintln!("Could not read {}: {}", source, e);
+        process::exit(1);
+    });
+
+    if let Err(e) = solution.validate_input(&input) {
+        eprintln!("Day {} input ({}) does not look right: {}", day, source, e);
+        process::exit(1);
+    }
+
+    let mut artifact = None;
+
+    for part in Part::ALL.iter().copied().filter(|&part| args.runs_part(part)) {
+        STAGE.store(part.number(), Ordering::SeqCst);
+
+        let answer = match engine_for(&args, day, part) {
+            Some(engine) => (engine.run)(&input),
+            None => {
+                if artifact.is_none() {
+                    artifact = solution.artifact(&input);
+                }
+
+                match part {
+                    Part::One => solution.part1_with(&input, artifact.as_ref()),
+                    Part::Two => solution.part2_with(&input, artifact.as_ref())
+                }
+            }
+        };
+
+        println!("Part {}: {}", part, answer);
+    }
+}
+
+// An engine name has to belong to the day, even if only for the other part
+fn check_engine(day: Day, name: &str) -> Result<(), String> {
+    let engines = engines(day);
+
+    if engines.iter().any(|e| e.name == name) {
+        return Ok(());
+    }
+
+    if engines.is_empty() {
+        Err(format!("There is no {} engine, day {} has no alternative engines", name, day))
+    } else {
+        let available = engines.iter()
+            .map(|e| format!("{} (part {})", e.name, e.part))
+            .collect::<Vec<String>>();
+
+        Err(format!("There is no {} engine, day {} has: {}", name, day, available.join(", ")))
+    }
+}
+
+// Parts without the named engine, and days without a viz engine under
+// `--viz`, run the day's own solution
+fn engine_for(args: &Args, day: Day, part: Part) -> Option<&'static Engine> {
+    let name = match &args.engine {
+        Some(name) => name.as_str(),
+        None if args.viz => VIZ_ENGINE,
+        None => return None
+    };
+
+    find_engine(day, part, name)
+}
+
+pub fn get_input() -> Result<String, std::io::Error> {
+    Input::File(INPUT_PATH.into()).read()
+}
+
+fn install_panic_hook(day: Day, source: String) {
+    let default_hook = panic::take_hook();
+
+    panic::set_hook(Box::new(move |info| {
+        let stage = match STAGE.load(Ordering::SeqCst) {
+            0 => "reading input".to_string(),
+            part => format!("part {}", part)
+        };
+
+        eprintln!("Day {} failed during {} (input: {})", day, stage, source);
+
+        if let Some(state) = intcode::take_panicked_state() {
+            eprintln!("Intcode machine at {}", state);
+        }
+
+        default_hook(info);
+    }));
+}
@@ -0,0 +1,166 @@
+use std::cmp;
+use std::ops::Range;
+use std::sync::atomic::{AtomicI64, AtomicU64, AtomicUsize, Ordering};
+use std::thread;
+
+// Returns the smallest value in the range matching the predicate. Chunks are
+// handed out in ascending order, so once a match is found no thread needs to
+// start a chunk beyond it.
+pub fn parallel_find<P>(range: Range<i64>, chunk_size: i64, predicate: P) -> Option<i64>
+where
+    P: Fn(i64) -> bool + Sync
+{
+    let chunks = Chunks::new(range, chunk_size);
+    let found = AtomicI64::new(i64::MAX);
+
+    thread::scope(|s| {
+        for _ in 0..worker_count() {
+            s.spawn(|| {
+                while let Some(mut chunk) = chunks.next() {
+                    if chunk.start > found.load(Ordering::SeqCst) {
+                        break;
+                    }
+
+                    if let Some(i) = chunk.find(|&i| predicate(i)) {
+                        found.fetch_min(i, Ordering::SeqCst);
+                        break;
+                    }
+                }
+            });
+        }
+    });
+
+    // The range end is exclusive, so i64::MAX itself can never be a match
+    match found.into_inner() {
+        i64::MAX => None,
+        i => Some(i)
+    }
+}
+
+pub fn parallel_count<P>(range: Range<i64>, chunk_size: i64, predicate: P) -> usize
+where
+    P: Fn(i64) -> bool + Sync
+{
+    let chunks = Chunks::new(range, chunk_size);
+    let count = AtomicUsize::new(0);
+
+    thread::scope(|s| {
+        for _ in 0..worker_count() {
+            s.spawn(|| {
+                while let Some(chunk) = chunks.next() {
+                    count.fetch_add(chunk.filter(|&i| predicate(i)).count(), Ordering::SeqCst);
+                }
+            });
+        }
+    });
+
+    count.into_inner()
+}
+
+// Hands out consecutive chunks of a range in ascending order. Progress is kept
+// as an offset from the start that never goes past the range's length, so
+// nothing wraps however close the range is to the ends of an i64.
+struct Chunks {
+    start: i64,
+    len: u64,
+    chunk_size: u64,
+    next: AtomicU64
+}
+
+impl Chunks {
+    fn new(range: Range<i64>, chunk_size: i64) -> Chunks {
+        assert!(chunk_size > 0, "chunk_size must be positive");
+
+        Chunks {
+            start: range.start,
+            len: if range.end > range.start { range.end.abs_diff(range.start) } else { 0 },
+            chunk_size: chunk_size as u64,
+            next: AtomicU64::new(0)
+        }
+    }
+
+    fn next(&self) -> Option<Range<i64>> {
+        let offset = self.next
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |offset| {
+                if offset >= self.len {
+                    None
+                } else {
+                    Some(cmp::min(offset.saturating_add(self.chunk_size), self.len))
+                }
+            })
+            .ok()?;
+
+        let end = cmp::min(offset.saturating_add(self.chunk_size), self.len);
+
+        // Both offsets are within the range, so adding them to its start lands
+        // back inside it
+        Some(self.start.wrapping_add(offset as i64)..self.start.wrapping_add(end as i64))
+    }
+}
+
+fn worker_count() -> usize {
+    thread::available_parallelism().map(|n| n.get()).unwrap_or(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_smallest_match_wins_across_threads() {
+        let matches = [99_999, 51_234, 70_000, 51_235];
+
+        for chunk_size in [1, 7, 1_000, 1_000_000] {
+            assert_eq!(parallel_find(0..100_000, chunk_size, |i| matches.contains(&i)), Some(51_234));
+        }
+
+        assert_eq!(parallel_find(-50..50, 3, |i| i % 10 == 0), Some(-50));
+    }
+
+    #[test]
+    fn finding_stops_soon_after_a_match() {
+        let checked = AtomicUsize::new(0);
+
+        let found = parallel_find(0..i64::MAX, 100, |i| {
+            checked.fetch_add(1, Ordering::SeqCst);
+            i == 250
+        });
+
+        assert_eq!(found, Some(250));
+
+        // Every worker finishes at most the chunk it was on, plus one more it
+        // may have taken before seeing the match
+        assert!(checked.into_inner() <= 300 + worker_count() * 200);
+    }
+
+    #[test]
+    fn empty_ranges_have_nothing_to_find_or_count() {
+        let backwards = Range { start: 10, end: 0 };
+
+        assert_eq!(parallel_find(5..5, 10, |_| true), None);
+        assert_eq!(parallel_find(backwards.clone(), 10, |_| true), None);
+        assert_eq!(parallel_count(5..5, 10, |_| true), 0);
+        assert_eq!(parallel_count(backwards, 10, |_| true), 0);
+    }
+
+    #[test]
+    fn ranges_near_the_ends_of_i64_stay_inside() {
+        let top = (i64::MAX - 10)..i64::MAX;
+
+        assert_eq!(parallel_find(top.clone(), 100, |i| i < 0), None);
+        assert_eq!(parallel_find(top.clone(), 3, |i| i >= i64::MAX - 2), Some(i64::MAX - 2));
+        assert_eq!(parallel_count(top.clone(), 100, |_| true), 10);
+        assert_eq!(parallel_count(top, 3, |i| !(i64::MAX - 10..i64::MAX).contains(&i)), 0);
+
+        let bottom = i64::MIN..(i64::MIN + 10);
+
+        assert_eq!(parallel_find(bottom.clone(), i64::MAX, |_| true), Some(i64::MIN));
+        assert_eq!(parallel_count(bottom, 4, |_| true), 10);
+    }
+
+    #[test]
+    fn counts_every_match_once() {
+        assert_eq!(parallel_count(0..100_003, 17, |i| i % 3 == 0), 33_335);
+        assert_eq!(parallel_count(i64::MIN..i64::MIN + 1, 1, |_| true), 1);
+    }
+}
@@ -0,0 +1,272 @@
+use std::any::Any;
+
+use crate::{Day, Part};
+
+// Something worked out for part 1 that part 2 can reuse instead of working it
+// out again, such as day 10's monitoring station
+pub type Artifact = Box<dyn Any + Send + Sync>;
+
+pub trait Solution: Sync {
+    fn day(&self) -> Day;
+
+    fn part1(&self, input: &str) -> String;
+
+    fn part2(&self, input: &str) -> String;
+
+    fn validate_input(&self, _input: &str) -> Result<(), String> {
+        Ok(())
+    }
+
+    // Days that share work between their parts produce it here, the runner
+    // then hands it to whichever part runs
+    fn artifact(&self, _input: &str) -> Option<Artifact> {
+        None
+    }
+
+    fn part1_with(&self, input: &str, _artifact: Option<&Artifact>) -> String {
+        self.part1(input)
+    }
+
+    fn part2_with(&self, input: &str, _artifact: Option<&Artifact>) -> String {
+        self.part2(input)
+    }
+}
+
+// Calls `part` with the artifact if it is there and of the right type,
+// otherwise produces it first
+pub fn with_artifact<A, R, P, F>(artifact: Option<&Artifact>, produce: P, part: F) -> R
+where
+    A: Any,
+    P: FnOnce() -> A,
+    F: FnOnce(&A) -> R
+{
+    match artifact.and_then(|a| a.downcast_ref::<A>()) {
+        Some(a) => part(a),
+        None => part(&produce())
+    }
+}
+
+pub struct Registration(pub &'static dyn Solution);
+
+inventory::collect!(Registration);
+
+// Every registered solution, ordered by day
+pub fn solutions() -> Vec<&'static dyn Solution> {
+    let mut solutions = inventory::iter::<Registration>
+        .into_iter()
+        .map(|r| r.0)
+        .collect::<Vec<_>>();
+
+    solutions.sort_by_key(|s| s.day());
+
+    solutions
+}
+
+pub fn find(day: Day) -> Option<&'static dyn Solution> {
+    inventory::iter::<Registration>
+        .into_iter()
+        .map(|r| r.0)
+        .find(|s| s.day() == day)
+}
+
+// An alternative implementation of one of a day's parts, picked by name with
+// `--engine`. Registered with `engine!`.
+pub struct Engine {
+    pub day: Day,
+    pub part: Part,
+    pub name: &'static str,
+    pub run: fn(&str) -> String
+}
+
+inventory::collect!(Engine);
+
+// The engines a day offers, for any of its parts
+pub fn engines(day: Day) -> Vec<&'static Engine> {
+    inventory::iter::<Engine>
+        .into_iter()
+        .filter(|e| e.day == day)
+        .collect()
+}
+
+pub fn find_engine(day: Day, part: Part, name: &str) -> Option<&'static Engine> {
+    inventory::iter::<Engine>
+        .into_iter()
+        .find(|e| e.day == day && e.part == part && e.name == name)
+}
+
+// Registers `$solve` as the engine called `$name` for part `$part` of a day,
+// taking the same parsed input as the day's own parts
+#[macro_export]
+macro_rules! engine {
+    ($day:expr, $part:expr, $name:expr, $parse:path, $solve:path) => {
+        $crate::inventory::submit! {
+            $crate::Engine {
+                day: $crate::Day::new($day),
+                part: $crate::Part::new($part),
+                name: $name,
+                run: |input| {
+                    use std::borrow::Borrow;
+
+                    $solve($parse(input).borrow()).to_string()
+                }
+            }
+        }
+    };
+}
+
+// Defines a `Day` type implementing Solution from a day's parse and part
+// functions and registers it, optionally with an input validator. Days that
+// share an artifact between parts also name its producer and the part
+// functions taking it. The artifact keeps the parsed input next to what was
+// produced from it, so neither part parses again once it's there.
+#[macro_export]
+macro_rules! solution {
+    ($day:expr, $parse:path, $part1:path, $part2:path) => {
+        $crate::solution!($day, $parse, $part1, $part2, |_: &str| Ok(()));
+    };
+    // The parsed input paired with what was produced from it
+    (@produce $parse:path, $produce:path, $input:expr) => {{
+        use std::borrow::Borrow;
+
+        let parsed = $parse($input);
+        let produced = $produce(parsed.borrow());
+
+        (parsed, produced)
+    }};
+    ($day:expr, $parse:path, $part1:path, $part2:path, $validate:expr) => {
+        pub struct Day;
+
+        impl $crate::Solution for Day {
+            fn day(&self) -> $crate::Day {
+                $crate::Day::new($day)
+            }
+
+            fn part1(&self, input: &str) -> String {
+                use std::borrow::Borrow;
+
+                $part1($parse(input).borrow()).to_string()
+            }
+
+            fn part2(&self, input: &str) -> String {
+                use std::borrow::Borrow;
+
+                $part2($parse(input).borrow()).to_string()
+            }
+
+            fn validate_input(&self, input: &str) -> Result<(), String> {
+                ($validate)(input)
+            }
+        }
+
+        $crate::inventory::submit! {
+            $crate::Registration(&Day)
+        }
+    };
+    ($day:expr, $parse:path, $part1:path, $part2:path, $validate:expr, artifact: $produce:path => $part1_with:path, $part2_with:path) => {
+        pub struct Day;
+
+        impl $crate::Solution for Day {
+            fn day(&self) -> $crate::Day {
+                $crate::Day::new($day)
+            }
+
+            fn part1(&self, input: &str) -> String {
+                use std::borrow::Borrow;
+
+                $part1($parse(input).borrow()).to_string()
+            }
+
+            fn part2(&self, input: &str) -> String {
+                use std::borrow::Borrow;
+
+                $part2($parse(input).borrow()).to_string()
+            }
+
+            fn validate_input(&self, input: &str) -> Result<(), String> {
+                ($validate)(input)
+            }
+
+            fn artifact(&self, input: &str) -> Option<$crate::Artifact> {
+                Some(Box::new($crate::solution!(@produce $parse, $produce, input)))
+            }
+
+            fn part1_with(&self, input: &str, artifact: Option<&$crate::Artifact>) -> String {
+                use std::borrow::Borrow;
+
+                let produce = || $crate::solution!(@produce $parse, $produce, input);
+
+                $crate::with_artifact(artifact, produce, |(parsed, a)| $part1_with(parsed.borrow(), a)).to_string()
+            }
+
+            fn part2_with(&self, input: &str, artifact: Option<&$crate::Artifact>) -> String {
+                use std::borrow::Borrow;
+
+                let produce = || $crate::solution!(@produce $parse, $produce, input);
+
+                $crate::with_artifact(artifact, produce, |(parsed, a)| $part2_with(parsed.borrow(), a)).to_string()
+            }
+        }
+
+        $crate::inventory::submit! {
+            $crate::Registration(&Day)
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use crate::Solution;
+
+    static PARSES: AtomicUsize = AtomicUsize::new(0);
+    static PRODUCED: AtomicUsize = AtomicUsize::new(0);
+
+    fn parse(input: &str) -> Vec<i64> {
+        PARSES.fetch_add(1, Ordering::SeqCst);
+
+        input.split(',').map(|n| n.parse().unwrap()).collect()
+    }
+
+    fn total(numbers: &[i64]) -> i64 {
+        PRODUCED.fetch_add(1, Ordering::SeqCst);
+
+        numbers.iter().sum()
+    }
+
+    fn part1(numbers: &[i64]) -> i64 {
+        part1_with(numbers, &total(numbers))
+    }
+
+    fn part2(numbers: &[i64]) -> i64 {
+        part2_with(numbers, &total(numbers))
+    }
+
+    fn part1_with(_numbers: &[i64], total: &i64) -> i64 {
+        *total
+    }
+
+    fn part2_with(numbers: &[i64], total: &i64) -> i64 {
+        total * numbers.len() as i64
+    }
+
+    crate::solution!(25, parse, part1, part2, |_: &str| Ok(()), artifact: total => part1_with, part2_with);
+
+    #[test]
+    fn an_artifact_spares_both_parts_from_parsing_again() {
+        let artifact = Day.artifact("1,2,3");
+
+        assert_eq!(Day.part1_with("1,2,3", artifact.as_ref()), "6");
+        assert_eq!(Day.part2_with("1,2,3", artifact.as_ref()), "18");
+        assert_eq!(PARSES.load(Ordering::SeqCst), 1);
+        assert_eq!(PRODUCED.load(Ordering::SeqCst), 1);
+
+        // Without one each part works everything out itself
+        assert_eq!(Day.part2_with("1,2,3", None), "18");
+        assert_eq!(PARSES.load(Ordering::SeqCst), 2);
+        assert_eq!(PRODUCED.load(Ordering::SeqCst), 2);
+
+        assert_eq!(Day.part1("1,2,3"), "6");
+        assert_eq!(PARSES.load(Ordering::SeqCst), 3);
+    }
+}
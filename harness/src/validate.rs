@@ -0,0 +1,84 @@
+// Accepts what intcode::parse does, a trailing comma included, as long as
+// there is at least one code
+pub fn intcode_program(input: &str) -> Result<(), String> {
+    if input.trim().is_empty() {
+        return Err("expected comma separated integers, found nothing".to_string());
+    }
+
+    for (i, code) in input.trim().split_terminator(',').enumerate() {
+        if code.trim().parse::<i64>().is_err() {
+            let excerpt: String = code.chars().take(20).collect();
+
+            return Err(format!("expected comma separated integers, found {:?} at position {}", excerpt, i));
+        }
+    }
+
+    Ok(())
+}
+
+pub fn digits(input: &str) -> Result<(), String> {
+    match input.trim_end().chars().position(|c| !c.is_ascii_digit()) {
+        Some(i) => Err(format!("expected only digits, found a non-digit at offset {}", i)),
+        None => Ok(())
+    }
+}
+
+pub fn grid(input: &str, allowed: &str) -> Result<(), String> {
+    let mut width = None;
+
+    for (y, line) in input.lines().enumerate() {
+        let line = line.trim();
+
+        if let Some(c) = line.chars().find(|&c| !allowed.contains(c)) {
+            return Err(format!("unexpected character {:?} on line {}", c, y + 1));
+        }
+
+        match width {
+            None => width = Some(line.len()),
+            Some(w) if w != line.len() => {
+                return Err(format!("line {} is {} wide, expected {}", y + 1, line.len(), w));
+            },
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_what_intcode_parses() {
+        for input in ["1,0,0,3,99", "1,0,0,3,99\n", "1, 0, 0, 3, 99", "1,0,0,3,99,", "1,0,0,3,99,\n", "-1,2"] {
+            assert_eq!(intcode_program(input), Ok(()), "{:?}", input);
+            assert!(!intcode::parse(input).is_empty());
+        }
+
+        assert_eq!(intcode::parse("1,0,0,3,99,\n"), vec![1, 0, 0, 3, 99]);
+    }
+
+    #[test]
+    fn rejects_anything_but_codes() {
+        assert_eq!(intcode_program(""), Err("expected comma separated integers, found nothing".to_string()));
+        assert_eq!(intcode_program(" \n"), Err("expected comma separated integers, found nothing".to_string()));
+        assert_eq!(intcode_program("1,,2"), Err("expected comma separated integers, found \"\" at position 1".to_string()));
+        assert_eq!(intcode_program("1,2,,"), Err("expected comma separated integers, found \"\" at position 2".to_string()));
+        assert_eq!(intcode_program("1,x,3"), Err("expected comma separated integers, found \"x\" at position 1".to_string()));
+        assert_eq!(intcode_program("1 2 3"), Err("expected comma separated integers, found \"1 2 3\" at position 0".to_string()));
+    }
+
+    #[test]
+    fn checks_digits() {
+        assert_eq!(digits("0123\n"), Ok(()));
+        assert_eq!(digits("01a3"), Err("expected only digits, found a non-digit at offset 2".to_string()));
+    }
+
+    #[test]
+    fn checks_grids() {
+        assert_eq!(grid(".#.\n#..\n", ".#"), Ok(()));
+        assert_eq!(grid(".#.\n#x.\n", ".#"), Err("unexpected character 'x' on line 2".to_string()));
+        assert_eq!(grid(".#.\n#.\n", ".#"), Err("line 2 is 2 wide, expected 3".to_string()));
+    }
+}
@@ -0,0 +1,111 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use crate::program::disassemble;
+use crate::{Interpreter, Program};
+
+// Which addresses of a program image were ever executed. Call `record` with
+// the machine running the image before each step, then render the result
+// with `listing`.
+#[derive(Debug, Clone)]
+pub struct Coverage {
+    image: Program,
+    executed: BTreeMap<i64, Executed>
+}
+
+// An instruction as it looked when it ran, which for self-modifying code
+// isn't necessarily what the image holds
+#[derive(Debug, Clone)]
+struct Executed {
+    text: String,
+    width: i64,
+    modified: bool
+}
+
+impl Coverage {
+    // Takes the image as loaded rather than a machine's memory, which may be
+    // sparse or have grown past the end of the program
+    pub fn new(image: Program) -> Coverage {
+        Coverage {
+            image,
+            executed: BTreeMap::new()
+        }
+    }
+
+    pub fn record(&mut self, machine: &Interpreter) {
+        let addr = machine.position;
+        let op = machine.parse_opcode(machine.fetch(addr));
+        let width = op.width();
+
+        let operands = (1..width)
+            .map(|i| machine.fetch(addr + i))
+            .collect::<Vec<i64>>();
+
+        let text = disassemble(&op, &operands);
+
+        match self.executed.get_mut(&addr) {
+            Some(seen) => seen.modified |= seen.text != text,
+            None => {
+                self.executed.insert(addr, Executed { text, width, modified: false });
+            }
+        }
+    }
+
+    pub fn is_executed(&self, addr: i64) -> bool {
+        self.executed.contains_key(&addr)
+    }
+
+    pub fn executed_count(&self) -> usize {
+        self.executed.len()
+    }
+
+    // The program image as a disassembly, executed instructions marked with
+    // `*` and ones that changed between runs with `!`. Everything that never
+    // ran is shown as raw cells since there's no telling code from data there.
+    pub fn listing(&self) -> String {
+        let mut listing = String::new();
+        let mut addr = 0;
+        let len = self.image.len() as i64;
+
+        let reached = self.executed.keys().filter(|&&a| a < len).count();
+        writeln!(listing, "; {} of {} cells start an executed instruction", reached, len).unwrap();
+
+        while addr < len {
+            if let Some(executed) = self.executed.get(&addr) {
+                let marker = if executed.modified { '!' } else { '*' };
+
+                writeln!(listing, "{} {:05}  {}", marker, addr, executed.text).unwrap();
+
+                addr += executed.width;
+            } else {
+                writeln!(listing, "  {:05}  {}", addr, self.image.codes()[addr as usize]).unwrap();
+
+                addr += 1;
+            }
+        }
+
+        listing
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lists_only_the_image_when_memory_grows_past_it() {
+        // Writes 7 to address 1000, then halts
+        let codes = vec![1101, 3, 4, 1000, 99];
+
+        let mut coverage = Coverage::new(Program::new(codes.clone()));
+        let mut machine = Interpreter::new(codes, vec![]);
+
+        while machine.is_running() {
+            coverage.record(&machine);
+            machine.step();
+        }
+
+        assert_eq!(machine.fetch(1000), 7);
+        assert_eq!(coverage.listing(), "; 2 of 5 cells start an executed instruction\n* 00000  add 3, 4, [1000]\n* 00004  hlt\n");
+    }
+}
@@ -0,0 +1,110 @@
+use crate::{Interpreter, VecOutput};
+
+// What one candidate input led to: the outputs produced along the way and the
+// machine as it was left, ready to be explored further
+#[derive(Debug, Clone)]
+pub struct Branch {
+    pub input: i64,
+    pub outputs: Vec<i64>,
+    pub machine: Interpreter
+}
+
+impl Interpreter {
+    // Forks this machine once per candidate input and runs each fork until it
+    // halts, asks for input again or uses up its step budget. The original
+    // machine is left untouched. The candidates are pushed onto the machine's
+    // input queue, so it panics for sources without one, see `push_input`.
+    pub fn explore(&self, candidates: &[i64], max_steps: usize) -> Vec<Branch> {
+        assert!(self.is_awaiting_input(), "Can only explore from a machine that is waiting for input");
+
+        candidates.iter()
+            .map(|&input| {
+                let mut machine = self.clone();
+                let seen = machine.outputs().len();
+
+                // Forks collect their own output rather than sending it on to
+                // wherever the original machine's goes
+                machine.set_output(VecOutput::new(self.outputs().to_vec()));
+
+                machine.push_input(input);
+
+                for _ in 0..max_steps {
+                    machine.step();
+
+                    if !machine.is_running() || machine.is_awaiting_input() {
+                        break;
+                    }
+                }
+
+                Branch {
+                    input,
+                    outputs: machine.outputs()[seen..].to_vec(),
+                    machine
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Reads a guess, prints 1 if it was 7 and 0 otherwise, then asks again
+    const GUESS: [i64; 13] = [3, 11, 1008, 11, 7, 12, 4, 12, 3, 11, 99, 0, 0];
+
+    // Takes an input, then counts up in cell 9 forever
+    const COUNTER: [i64; 10] = [3, 9, 1001, 9, 1, 9, 1105, 1, 2, 0];
+
+    fn waiting(program: &[i64]) -> Interpreter {
+        let mut machine = Interpreter::new(program.to_vec(), vec![]);
+        machine.run_until_input();
+        machine
+    }
+
+    #[test]
+    fn finds_the_candidate_that_gives_the_output() {
+        let machine = waiting(&GUESS);
+        let branches = machine.explore(&[5, 7, 9], 100);
+
+        assert_eq!(branches.iter().map(|b| b.outputs.clone()).collect::<Vec<_>>(), vec![vec![0], vec![1], vec![0]]);
+        assert_eq!(branches.iter().find(|b| b.outputs == [1]).map(|b| b.input), Some(7));
+
+        // Each fork stops at the next input, ready to go on
+        assert!(branches.iter().all(|b| b.machine.is_awaiting_input()));
+    }
+
+    #[test]
+    fn leaves_the_original_machine_alone() {
+        let machine = waiting(&GUESS);
+        let before = machine.clone();
+
+        machine.explore(&[7], 100);
+
+        assert_eq!(machine, before);
+        assert!(machine.is_awaiting_input());
+    }
+
+    #[test]
+    fn stops_at_the_step_budget() {
+        let machine = waiting(&COUNTER);
+        let branch = machine.explore(&[0], 11).remove(0);
+
+        // The input, then five rounds of add and jump
+        assert!(branch.machine.is_running());
+        assert!(!branch.machine.is_awaiting_input());
+        assert_eq!(branch.machine.fetch(9), 5);
+    }
+
+    #[test]
+    fn forks_only_report_their_own_output() {
+        let mut machine = Interpreter::new(vec![104, 42, 3, 9, 4, 9, 99, 0, 0, 0], vec![]);
+        machine.run_until_input();
+
+        let branch = machine.explore(&[8], 100).remove(0);
+
+        assert_eq!(branch.outputs, vec![8]);
+        assert_eq!(branch.machine.outputs(), &[42, 8]);
+        assert!(!branch.machine.is_running());
+    }
+}
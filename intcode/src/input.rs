@@ -0,0 +1,103 @@
+use std::collections::VecDeque;
+use std::fmt;
+
+// Where a machine's input instructions get their values from
+pub trait InputSource: fmt::Debug + Send + CloneInputSource {
+    // The next value, `None` when there is nothing to give
+    fn next_input(&mut self) -> Option<i64>;
+
+    // Whether `next_input` has a value ready. Sources that make their values
+    // up on demand always do.
+    fn has_input(&self) -> bool {
+        true
+    }
+
+    // Queues a value behind the ones already there. Only sources that keep a
+    // queue can take one, the rest say no.
+    fn push(&mut self, _value: i64) -> bool {
+        false
+    }
+
+    // The values still waiting to be read, for sources that keep a queue
+    fn pending(&self) -> Option<&VecDeque<i64>> {
+        None
+    }
+}
+
+// Lets machines holding a boxed source still be cloned, implemented for every
+// source that is Clone
+pub trait CloneInputSource {
+    fn clone_box(&self) -> Box<dyn InputSource>;
+}
+
+impl<T: InputSource + Clone + 'static> CloneInputSource for T {
+    fn clone_box(&self) -> Box<dyn InputSource> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn InputSource> {
+    fn clone(&self) -> Box<dyn InputSource> {
+        self.clone_box()
+    }
+}
+
+// A queue handed out in order, what a machine reads from unless told
+// otherwise. More can be pushed onto the back while the machine runs.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct VecInput {
+    values: VecDeque<i64>
+}
+
+impl VecInput {
+    pub fn new(values: Vec<i64>) -> VecInput {
+        VecInput { values: values.into() }
+    }
+}
+
+impl InputSource for VecInput {
+    fn next_input(&mut self) -> Option<i64> {
+        self.values.pop_front()
+    }
+
+    fn has_input(&self) -> bool {
+        !self.values.is_empty()
+    }
+
+    fn push(&mut self, value: i64) -> bool {
+        self.values.push_back(value);
+
+        true
+    }
+
+    fn pending(&self) -> Option<&VecDeque<i64>> {
+        Some(&self.values)
+    }
+}
+
+// The same value every time, like a joystick held in one position
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConstInput(pub i64);
+
+impl InputSource for ConstInput {
+    fn next_input(&mut self) -> Option<i64> {
+        Some(self.0)
+    }
+}
+
+// Asks a closure for every value, for input that depends on what the program
+// has done so far
+#[derive(Clone)]
+pub struct FnInput<F>(pub F);
+
+impl<F> fmt::Debug for FnInput<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("FnInput")
+    }
+}
+
+impl<F: FnMut() -> Option<i64> + Clone + Send + 'static> InputSource for FnInput<F> {
+    fn next_input(&mut self) -> Option<i64> {
+        (self.0)()
+    }
+}
@@ -0,0 +1,825 @@
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::Range;
+use ansi_term::Colour::Red;
+
+use crate::input::{InputSource, VecInput};
+use crate::output::{OutputSink, VecOutput};
+
+#[derive(Debug, Clone)]
+pub struct Interpreter {
+    pub codes: HashMap<i64, i64>,
+    pub(crate) position: i64,
+    halted: bool,
+    input: Box<dyn InputSource>,
+    pub last_output: i64,
+    relative_base: i64,
+    output: Box<dyn OutputSink>,
+    level: Level,
+    protected: Vec<Range<i64>>,
+    trap: Option<Trap>
+}
+
+// The instruction set grew over the course of the puzzles; an interpreter
+// built for an earlier day refuses anything that day's machine didn't know
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum Level {
+    Day2,
+    Day5,
+    Day9
+}
+
+impl Level {
+    fn supports(self, op: &OpCode) -> bool {
+        match self {
+            Level::Day2 => matches!(op,
+                OpCode::Add(Mode::Position, Mode::Position, Mode::Position) |
+                OpCode::Multiply(Mode::Position, Mode::Position, Mode::Position) |
+                OpCode::Halt),
+            Level::Day5 => match op {
+                OpCode::AdjustBase(_) | OpCode::Noop => false,
+                _ => !op.modes().contains(&&Mode::Relative)
+            },
+            Level::Day9 => true
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Mode {
+    Position,
+    Immediate,
+    Relative
+}
+
+// What a single step did
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepResult {
+    Continue,
+    Output(i64),
+    // Stopped at an input instruction with nothing to read
+    NeedsInput,
+    Halted,
+    // Stopped on an instruction it isn't allowed to run, see `Interpreter::trap`
+    Trap(Trap)
+}
+
+// Why a machine stopped without halting. The instruction at fault is left
+// unexecuted at the current position so the machine can be inspected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trap {
+    // A write into an address marked read-only with `protect`
+    ProtectedWrite { addr: i64 },
+    // A read or write below address 0
+    NegativeAddress { addr: i64 },
+    // An add or multiply whose result doesn't fit in an i64. Checked rather
+    // than wrapped so a run behaves the same in debug and release builds.
+    Overflow,
+    // The relative base, an address computed from it or the next position
+    // went past the ends of an i64
+    AddressOverflow
+}
+
+impl fmt::Display for Trap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Trap::ProtectedWrite { addr } => write!(f, "write to protected address {}", addr),
+            Trap::NegativeAddress { addr } => write!(f, "negative address {}", addr),
+            Trap::Overflow => write!(f, "arithmetic overflow"),
+            Trap::AddressOverflow => write!(f, "address overflow")
+        }
+    }
+}
+
+// Where a machine was when it panicked, for panic hooks to report
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MachineState {
+    pub position: i64,
+    pub relative_base: i64,
+    // The raw value at `position`, `None` when that isn't a valid address
+    pub opcode: Option<i64>
+}
+
+impl fmt::Display for MachineState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "position {}, relative base {}, opcode ", self.position, self.relative_base)?;
+
+        match self.opcode {
+            Some(opcode) => write!(f, "{}", opcode),
+            None => write!(f, "unreadable")
+        }
+    }
+}
+
+thread_local! {
+    static PANICKED: Cell<Option<MachineState>> = const { Cell::new(None) };
+}
+
+// The state of the last machine to panic on this thread, cleared once taken
+pub fn take_panicked_state() -> Option<MachineState> {
+    PANICKED.with(Cell::take)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum OpCode {
+    Add(Mode, Mode, Mode),
+    Multiply(Mode, Mode, Mode),
+    Halt,
+    Input(Mode),
+    Output(Mode),
+    JumpIfTrue(Mode, Mode),
+    JumpIfFalse(Mode, Mode),
+    LessThan(Mode, Mode, Mode),
+    Equals(Mode, Mode, Mode),
+    AdjustBase(Mode),
+    Noop
+}
+
+// Memory is compared as if unset cells held zero, so two machines that only
+// differ in which zeroes happen to be stored in the map are still equal.
+// Input and output are compared by the values still queued and the values
+// collected so far. Sources and sinks that keep neither, like closures and
+// channels, are left out, so machines that only differ in those compare equal.
+impl PartialEq for Interpreter {
+    fn eq(&self, other: &Interpreter) -> bool {
+        let same_memory = |a: &HashMap<i64, i64>, b: &HashMap<i64, i64>| {
+            a.iter().all(|(p, &v)| b.get(p).copied().unwrap_or(0) == v)
+        };
+
+        self.position == other.position &&
+            self.halted == other.halted &&
+            self.input.pending() == other.input.pending() &&
+            self.last_output == other.last_output &&
+            self.relative_base == other.relative_base &&
+            self.output.collected() == other.output.collected() &&
+            self.level == other.level &&
+            self.protected == other.protected &&
+            self.trap == other.trap &&
+            same_memory(&self.codes, &other.codes) &&
+            same_memory(&other.codes, &self.codes)
+    }
+}
+
+impl Eq for Interpreter {}
+
+impl OpCode {
+    // Cells taken up by the instruction, opcode included
+    pub(crate) fn width(&self) -> i64 {
+        self.modes().len() as i64 + 1
+    }
+
+    pub(crate) fn modes(&self) -> Vec<&Mode> {
+        match self {
+            OpCode::Add(a, b, c) |
+            OpCode::Multiply(a, b, c) |
+            OpCode::LessThan(a, b, c) |
+            OpCode::Equals(a, b, c) => vec![a, b, c],
+            OpCode::JumpIfTrue(a, b) |
+            OpCode::JumpIfFalse(a, b) => vec![a, b],
+            OpCode::Input(a) |
+            OpCode::Output(a) |
+            OpCode::AdjustBase(a) => vec![a],
+            OpCode::Halt | OpCode::Noop => vec![]
+        }
+    }
+}
+
+impl Interpreter {
+    pub fn new(codes: Vec<i64>, input: Vec<i64>) -> Interpreter {
+        Interpreter::with_level(codes, input, Level::Day9)
+    }
+
+    pub fn with_level(codes: Vec<i64>, input: Vec<i64>, level: Level) -> Interpreter {
+        let mut code_dict: HashMap<i64, i64> = HashMap::new();
+
+        for (i, c) in codes.iter().enumerate() {
+            code_dict.insert(i as i64, *c);
+        }
+
+        Interpreter {
+            codes: code_dict,
+            position: 0,
+            halted: false,
+            input: Box::new(VecInput::new(input)),
+            last_output: 0,
+            relative_base: 0,
+            output: Box::new(VecOutput::default()),
+            level,
+            protected: Vec::new(),
+            trap: None
+        }
+    }
+
+    // Puts this machine back into the state of another, reusing the existing
+    // allocations rather than cloning fresh ones
+    pub(crate) fn reset_from(&mut self, other: &Interpreter) {
+        self.codes.clone_from(&other.codes);
+        self.position = other.position;
+        self.halted = other.halted;
+        self.input = other.input.clone();
+        self.last_output = other.last_output;
+        self.relative_base = other.relative_base;
+        self.output = other.output.clone();
+        self.level = other.level;
+        self.protected.clone_from(&other.protected);
+        self.trap = other.trap;
+    }
+
+    // Marks addresses as read-only. An instruction writing into them traps
+    // instead, stopping the machine right before it.
+    pub fn protect(&mut self, range: Range<i64>) {
+        self.protected.push(range);
+    }
+
+    // What stopped the machine, if it trapped
+    pub fn trap(&self) -> Option<Trap> {
+        self.trap
+    }
+
+    // Reads input from `source` from now on instead, whatever the old source
+    // still had is dropped
+    pub fn set_input<S: InputSource + 'static>(&mut self, source: S) {
+        self.input = Box::new(source);
+    }
+
+    // Writes output to `sink` from now on instead
+    pub fn set_output<S: OutputSink + 'static>(&mut self, sink: S) {
+        self.output = Box::new(sink);
+    }
+
+    // Everything output so far, empty when the sink doesn't keep its values
+    pub fn outputs(&self) -> &[i64] {
+        self.output.collected().unwrap_or(&[])
+    }
+
+    // Queues a value for a later input instruction, after anything already
+    // queued. Can be called while the machine is running. Panics when the
+    // input source doesn't keep a queue, like a `ConstInput` or `FnInput` set
+    // with `set_input`.
+    pub fn push_input(&mut self, input: i64) {
+        if !self.input.push(input) {
+            self.fail(format!("The input source {:?} does not take queued input", self.input));
+        }
+    }
+
+    // Queues each of `inputs` in turn, panicking like `push_input`
+    pub fn extend_inputs<I: IntoIterator<Item = i64>>(&mut self, inputs: I) {
+        for input in inputs {
+            self.push_input(input);
+        }
+    }
+
+    // True when the next instruction reads input and the source has nothing
+    // ready for it
+    pub fn is_awaiting_input(&self) -> bool {
+        self.is_running() &&
+            !self.input.has_input() &&
+            self.read(self.position).is_ok_and(|code| code % 100 == 3)
+    }
+
+    pub fn print_memory(&self) {
+        let mut code_vec = self.codes.iter().collect::<Vec<(&i64, &i64)>>();
+
+        code_vec.sort_by_key(|(&a,_)| a);
+
+        for (p, v) in code_vec {
+            if *p == 0 {
+                println!("\u{256D}{0}\u{252C}{0}\u{256E}", "\u{2500}\u{2500}\u{2500}\u{2500}\u{2500}\u{2500}\u{2500}");
+            } else {
+                println!("\u{251C}{0}\u{253C}{0}\u{2524}", "\u{2500}\u{2500}\u{2500}\u{2500}\u{2500}\u{2500}\u{2500}")
+            }
+
+            let mut p_str = format!("{:05}", p);
+
+            if *p == self.position {
+                p_str = Red.paint(p_str).to_string();
+            }
+
+            println!("\u{2502} {:05} \u{2502} {:05} \u{2502}", p_str, v);
+        }
+
+        println!("\u{2570}{0}\u{2534}{0}\u{256F}", "\u{2500}\u{2500}\u{2500}\u{2500}\u{2500}\u{2500}\u{2500}")
+    }
+
+    // False once the machine has halted or trapped
+    pub fn is_running(&self) -> bool {
+        !self.halted && self.trap.is_none()
+    }
+
+    // Runs one instruction and says what came of it. Reading input when the
+    // source has none leaves the machine where it is, so it can be stepped
+    // again once there is some. A trapped machine keeps returning its trap.
+    pub fn step(&mut self) -> StepResult {
+        if let Some(trap) = self.trap {
+            return StepResult::Trap(trap);
+        }
+
+        match self.execute() {
+            Ok(result) => result,
+            Err(trap) => {
+                self.trap = Some(trap);
+                StepResult::Trap(trap)
+            }
+        }
+    }
+
+    fn execute(&mut self) -> Result<StepResult, Trap> {
+        let op = self.parse_opcode(self.read(self.position)?);
+
+        if op == OpCode::Halt {
+            self.halted = true;
+            return Ok(StepResult::Halted);
+        }
+
+        // Past here every position is at most the instruction's width ahead
+        self.position.checked_add(op.width()).ok_or(Trap::AddressOverflow)?;
+
+        match op {
+            OpCode::Add(p1_mode, p2_mode, p3_mode) => {
+                let operand_1 = self.get_operand(self.position + 1, p1_mode)?;
+                let operand_2 = self.get_operand(self.position + 2, p2_mode)?;
+                let result = operand_1.checked_add(operand_2).ok_or(Trap::Overflow)?;
+                self.put(self.position + 3, result, p3_mode)?;
+
+                self.position += 4
+            }
+            OpCode::Multiply(p1_mode, p2_mode, p3_mode) => {
+                let operand_1 = self.get_operand(self.position + 1, p1_mode)?;
+                let operand_2 = self.get_operand(self.position + 2, p2_mode)?;
+                let result = operand_1.checked_mul(operand_2).ok_or(Trap::Overflow)?;
+                self.put(self.position + 3, result, p3_mode)?;
+
+                self.position += 4
+            }
+            OpCode::Halt => self.position += 1,
+            OpCode::Input(p1_mode) => {
+                // Checked before reading so a trap doesn't use up the value
+                let addr = self.write_address(self.position + 1, &p1_mode)?;
+                self.check_writable(addr)?;
+
+                let inp = match self.input.next_input() {
+                    Some(inp) => inp,
+                    None => return Ok(StepResult::NeedsInput)
+                };
+
+                self.write(addr, inp)?;
+
+                self.position += 2;
+            }
+            OpCode::Output(fetch_mode) => {
+                let output = self.get_operand(self.position + 1, fetch_mode)?;
+                self.last_output = output;
+
+                self.output.send(output);
+
+                self.position += 2;
+
+                return Ok(StepResult::Output(output));
+            },
+            OpCode::JumpIfTrue(p1_mode, p2_mode) => {
+                let comparison = self.get_operand(self.position + 1, p1_mode)?;
+                let to_jump = self.get_operand(self.position + 2, p2_mode)?;
+
+                if comparison != 0 {
+                    self.position = to_jump;
+                } else {
+                    self.position += 3;
+                }
+            },
+            OpCode::JumpIfFalse(p1_mode, p2_mode) => {
+                let comparison = self.get_operand(self.position + 1, p1_mode)?;
+                let to_jump = self.get_operand(self.position + 2, p2_mode)?;
+
+                if comparison == 0 {
+                    self.position = to_jump;
+                } else {
+                    self.position += 3;
+                }
+            },
+            OpCode::LessThan(p1_mode, p2_mode, p3_mode) => {
+                let comparison_1 = self.get_operand(self.position + 1, p1_mode)?;
+                let comparison_2 = self.get_operand(self.position + 2, p2_mode)?;
+
+                if comparison_1 < comparison_2 {
+                    self.put(self.position + 3, 1, p3_mode)?
+                } else {
+                    self.put(self.position + 3, 0, p3_mode)?
+                }
+
+                self.position += 4;
+            }
+            OpCode::Equals(p1_mode, p2_mode, p3_mode) => {
+                let comparison_1 = self.get_operand(self.position + 1, p1_mode)?;
+                let comparison_2 = self.get_operand(self.position + 2, p2_mode)?;
+
+                if comparison_1 == comparison_2 {
+                    self.put(self.position + 3, 1, p3_mode)?
+                } else {
+                    self.put(self.position + 3, 0, p3_mode)?
+                }
+
+                self.position += 4;
+            },
+            OpCode::AdjustBase(p1_mode) => {
+                let arg = self.get_operand(self.position + 1, p1_mode)?;
+
+                self.relative_base = self.relative_base.checked_add(arg).ok_or(Trap::AddressOverflow)?;
+
+                self.position += 2;
+            },
+            OpCode::Noop => {
+                self.position += 1;
+            }
+        };
+
+        Ok(StepResult::Continue)
+    }
+
+    // Runs the machine until it halts and returns what it output along the
+    // way. Running out of input is an error rather than a wait that would
+    // never end.
+    pub fn run(&mut self) -> Result<Vec<i64>, String> {
+        let mut outputs = Vec::new();
+
+        loop {
+            match self.step() {
+                StepResult::Output(value) => outputs.push(value),
+                StepResult::Continue => {}
+                StepResult::Halted => return Ok(outputs),
+                StepResult::NeedsInput => return Err(format!("Ran out of input at position {}", self.position)),
+                StepResult::Trap(trap) => return Err(format!("Trapped at position {}: {}", self.position, trap))
+            }
+        }
+    }
+
+    // Queues `inputs` behind any input already there, then runs to the end
+    pub fn run_with_input(&mut self, inputs: &[i64]) -> Result<Vec<i64>, String> {
+        self.extend_inputs(inputs.iter().copied());
+
+        self.run()
+    }
+
+    // Steps until the next output and returns it, or `None` if the machine
+    // halts or runs out of input first. A trap is an error.
+    pub fn run_until_output(&mut self) -> Result<Option<i64>, Trap> {
+        loop {
+            match self.step() {
+                StepResult::Output(value) => return Ok(Some(value)),
+                StepResult::NeedsInput | StepResult::Halted => return Ok(None),
+                StepResult::Trap(trap) => return Err(trap),
+                StepResult::Continue => {}
+            }
+        }
+    }
+
+    // Steps until the machine halts, traps or wants input it doesn't have,
+    // whichever of those happened is returned
+    pub fn run_until_input(&mut self) -> StepResult {
+        loop {
+            match self.step() {
+                result @ (StepResult::NeedsInput | StepResult::Halted | StepResult::Trap(_)) => return result,
+                StepResult::Output(_) | StepResult::Continue => {}
+            }
+        }
+    }
+
+    fn get_operand(&self, pos: i64, mode: Mode) -> Result<i64, Trap> {
+        match mode {
+            Mode::Immediate => self.read(pos),
+            Mode::Position => self.read(self.read(pos)?),
+            Mode::Relative => self.read(self.relative_address(pos)?)
+        }
+    }
+
+    fn put(&mut self, pos: i64, data: i64, mode: Mode) -> Result<(), Trap> {
+        let addr = self.write_address(pos, &mode)?;
+        self.write(addr, data)
+    }
+
+    pub(crate) fn write_address(&self, pos: i64, mode: &Mode) -> Result<i64, Trap> {
+        match mode {
+            Mode::Position => self.read(pos),
+            Mode::Relative => self.relative_address(pos),
+            _ => self.fail("Writing data may only be position or relative".to_string())
+        }
+    }
+
+    fn write(&mut self, addr: i64, data: i64) -> Result<(), Trap> {
+        if addr < 0 {
+            return Err(Trap::NegativeAddress { addr });
+        }
+
+        self.check_writable(addr)?;
+
+        self.codes.insert(addr, data);
+
+        Ok(())
+    }
+
+    fn check_writable(&self, addr: i64) -> Result<(), Trap> {
+        if self.protected.iter().any(|r| r.contains(&addr)) {
+            Err(Trap::ProtectedWrite { addr })
+        } else {
+            Ok(())
+        }
+    }
+
+    fn relative_address(&self, pos: i64) -> Result<i64, Trap> {
+        self.relative_base.checked_add(self.read(pos)?).ok_or(Trap::AddressOverflow)
+    }
+
+    fn get_digits(&self, number: i64) -> (Mode, Mode, Mode, i64) {
+        let mut digits: Vec<i64> = number
+            .to_string()
+            .chars()
+            .filter_map(|x| x.to_digit(10))
+            .map(|x| x as i64)
+            .collect();
+
+        digits.reverse();
+
+        while digits.len() < 5 {
+            digits.push(0);
+        }
+
+        digits.reverse();
+
+        (
+            self.parse_mode(digits[2]),
+            self.parse_mode(digits[1]),
+            self.parse_mode(digits[0]),
+            digits[3] * 10 + digits[4],
+        )
+    }
+
+    pub fn fetch(&self, pos: i64) -> i64 {
+        self.read(pos).unwrap_or_else(|trap| self.fail(format!("Fetching from a {} at position {}", trap, self.position)))
+    }
+
+    // Leaves the machine's state behind for the panic hook, then panics
+    fn fail(&self, message: String) -> ! {
+        let state = MachineState {
+            position: self.position,
+            relative_base: self.relative_base,
+            opcode: self.read(self.position).ok()
+        };
+
+        PANICKED.with(|panicked| panicked.set(Some(state)));
+
+        panic!("{}", message)
+    }
+
+    fn read(&self, pos: i64) -> Result<i64, Trap> {
+        if pos < 0 {
+            return Err(Trap::NegativeAddress { addr: pos });
+        }
+
+        Ok(*self.codes.get(&pos).unwrap_or(&0))
+    }
+
+    fn parse_mode(&self, mode: i64) -> Mode {
+        match mode {
+            0 => Mode::Position,
+            1 => Mode::Immediate,
+            2 => Mode::Relative,
+            _ => Mode::Position
+        }
+    }
+
+    pub(crate) fn parse_opcode(&self, raw: i64) -> OpCode {
+        let op = self.get_digits(raw);
+
+        let op = match op {
+            (p1_mode, p2_mode, p3_mode, 1) => OpCode::Add(p1_mode, p2_mode, p3_mode),
+            (p1_mode, p2_mode, p3_mode, 2) => OpCode::Multiply(p1_mode, p2_mode, p3_mode),
+            (p1_mode, _, _, 3) => OpCode::Input(p1_mode),
+            (p1_mode, _, _, 4) => OpCode::Output(p1_mode),
+            (p1_mode, p2_mode, _, 5) => OpCode::JumpIfTrue(p1_mode, p2_mode),
+            (p1_mode, p2_mode, _, 6) => OpCode::JumpIfFalse(p1_mode, p2_mode),
+            (p1_mode, p2_mode, p3_mode, 7) => OpCode::LessThan(p1_mode, p2_mode, p3_mode),
+            (p1_mode, p2_mode, p3_mode, 8) => OpCode::Equals(p1_mode, p2_mode, p3_mode),
+            (p1_mode, _, _, 9) => OpCode::AdjustBase(p1_mode),
+            (_, _, _, 99) => OpCode::Halt,
+            (_, _, _, 0) => OpCode::Noop,
+            _ => self.fail(format!("Unimplemented opcode: {:?}", op)),
+        };
+
+        if !self.level.supports(&op) {
+            self.fail(format!("Opcode {} at position {} is not supported at level {:?}", raw, self.position, self.level));
+        }
+
+        op
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::FnInput;
+    use crate::output::FnOutput;
+
+    #[test]
+    fn equal_machines_need_the_same_queued_input() {
+        let a = Interpreter::new(vec![3, 0, 99], vec![1, 2]);
+
+        assert_eq!(a, Interpreter::new(vec![3, 0, 99], vec![1, 2]));
+        assert_ne!(a, Interpreter::new(vec![3, 0, 99], vec![2, 1]));
+        assert_ne!(a, Interpreter::new(vec![3, 0, 99], vec![1]));
+    }
+
+    #[test]
+    fn equal_machines_need_the_same_collected_output() {
+        let mut a = Interpreter::new(vec![104, 1, 99], vec![]);
+        let mut b = a.clone();
+
+        a.step();
+        assert_ne!(a, b);
+
+        b.step();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn sources_and_sinks_without_values_are_left_out() {
+        let mut a = Interpreter::new(vec![99], vec![]);
+        let mut b = a.clone();
+
+        a.set_input(FnInput(|| Some(1)));
+        b.set_input(FnInput(|| Some(2)));
+        a.set_output(FnOutput(|_| {}));
+        b.set_output(FnOutput(|_| {}));
+
+        assert_eq!(a, b);
+
+        // A queue never matches a source without one
+        assert_ne!(a, Interpreter::new(vec![99], vec![]));
+    }
+
+    #[test]
+    fn protected_writes_trap_before_the_instruction_runs() {
+        // add 5 + 6 into 0, which is protected
+        let mut machine = Interpreter::new(vec![1101, 5, 6, 0, 99], vec![]);
+        machine.protect(0..4);
+
+        assert_eq!(machine.step(), StepResult::Trap(Trap::ProtectedWrite { addr: 0 }));
+        assert_eq!(machine.trap(), Some(Trap::ProtectedWrite { addr: 0 }));
+        assert!(!machine.is_running());
+        assert_eq!(machine.position, 0);
+        assert_eq!(machine.fetch(0), 1101);
+
+        // Stepping again doesn't get past it
+        assert_eq!(machine.step(), StepResult::Trap(Trap::ProtectedWrite { addr: 0 }));
+    }
+
+    #[test]
+    fn a_trapped_input_keeps_its_value() {
+        let mut machine = Interpreter::new(vec![3, 0, 99], vec![7]);
+        machine.protect(0..1);
+
+        assert_eq!(machine.run_until_input(), StepResult::Trap(Trap::ProtectedWrite { addr: 0 }));
+        assert_eq!(machine.input.pending(), Some(&vec![7].into()));
+    }
+
+    #[test]
+    fn traps_surface_from_the_run_helpers() {
+        let program = vec![104, 1, 1101, 1, 1, 10, 99];
+
+        let mut machine = Interpreter::new(program.clone(), vec![]);
+        machine.protect(10..11);
+
+        assert_eq!(machine.run(), Err("Trapped at position 2: write to protected address 10".to_string()));
+
+        let mut machine = Interpreter::new(program, vec![]);
+        machine.protect(10..11);
+
+        assert_eq!(machine.run_until_output(), Ok(Some(1)));
+        assert_eq!(machine.run_until_output(), Err(Trap::ProtectedWrite { addr: 10 }));
+        assert_eq!(machine.trap(), Some(Trap::ProtectedWrite { addr: 10 }));
+    }
+
+    #[test]
+    fn add_overflow_traps_instead_of_wrapping() {
+        let mut machine = Interpreter::new(vec![1101, i64::MAX, 1, 5, 99, 0], vec![]);
+
+        assert_eq!(machine.step(), StepResult::Trap(Trap::Overflow));
+        assert_eq!(machine.fetch(5), 0);
+
+        let mut machine = Interpreter::new(vec![1101, i64::MIN, -1, 5, 99, 0], vec![]);
+
+        assert_eq!(machine.run(), Err("Trapped at position 0: arithmetic overflow".to_string()));
+    }
+
+    #[test]
+    fn mul_overflow_traps_instead_of_wrapping() {
+        let mut machine = Interpreter::new(vec![1102, i64::MIN, -1, 5, 99, 0], vec![]);
+
+        assert_eq!(machine.step(), StepResult::Trap(Trap::Overflow));
+
+        let mut machine = Interpreter::new(vec![1102, i64::MAX, 2, 5, 99, 0], vec![]);
+
+        assert_eq!(machine.step(), StepResult::Trap(Trap::Overflow));
+
+        // Only the result matters, negative operands that fit are fine
+        let mut machine = Interpreter::new(vec![1102, i64::MIN, 1, 5, 99, 0], vec![]);
+
+        assert_eq!(machine.run(), Ok(vec![]));
+        assert_eq!(machine.fetch(5), i64::MIN);
+    }
+
+    #[test]
+    fn negative_absolute_addresses_trap() {
+        // Reading from -3
+        let mut machine = Interpreter::new(vec![4, -3, 99], vec![]);
+
+        assert_eq!(machine.step(), StepResult::Trap(Trap::NegativeAddress { addr: -3 }));
+
+        // Writing to -1
+        let mut machine = Interpreter::new(vec![1101, 1, 1, -1, 99], vec![]);
+
+        assert_eq!(machine.step(), StepResult::Trap(Trap::NegativeAddress { addr: -1 }));
+
+        // Jumping to -2 is fine until the next instruction is fetched
+        let mut machine = Interpreter::new(vec![1105, 1, -2], vec![]);
+
+        assert_eq!(machine.step(), StepResult::Continue);
+        assert!(!machine.is_awaiting_input());
+        assert_eq!(machine.step(), StepResult::Trap(Trap::NegativeAddress { addr: -2 }));
+    }
+
+    #[test]
+    fn relative_base_overflow_traps() {
+        let mut machine = Interpreter::new(vec![109, i64::MAX, 109, 1, 99], vec![]);
+
+        assert_eq!(machine.step(), StepResult::Continue);
+        assert_eq!(machine.step(), StepResult::Trap(Trap::AddressOverflow));
+        assert_eq!(machine.relative_base, i64::MAX);
+
+        // An address relative to the base overflowing
+        let mut machine = Interpreter::new(vec![109, i64::MIN, 204, -1, 99], vec![]);
+
+        assert_eq!(machine.step(), StepResult::Continue);
+        assert_eq!(machine.step(), StepResult::Trap(Trap::AddressOverflow));
+    }
+
+    #[test]
+    fn panicking_machines_leave_their_state_behind() {
+        let mut machine = Interpreter::with_level(vec![109, 4, 1, 0, 0, 0, 209, 1, 99], vec![], Level::Day9);
+        machine.step();
+        machine.position = 6;
+        machine.level = Level::Day5;
+
+        assert!(std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| machine.step())).is_err());
+
+        assert_eq!(take_panicked_state(), Some(MachineState { position: 6, relative_base: 4, opcode: Some(209) }));
+        assert_eq!(take_panicked_state(), None);
+    }
+
+    // Reads three values and outputs them in the order they came in
+    const ECHO_THREE: [i64; 13] = [3, 20, 3, 21, 3, 22, 4, 20, 4, 21, 4, 22, 99];
+
+    #[test]
+    fn queued_input_is_read_first_in_first_out() {
+        let mut machine = Interpreter::new(ECHO_THREE.to_vec(), vec![1]);
+
+        machine.push_input(2);
+        machine.extend_inputs(vec![3]);
+
+        assert_eq!(machine.run(), Ok(vec![1, 2, 3]));
+
+        let mut machine = Interpreter::new(ECHO_THREE.to_vec(), vec![]);
+
+        machine.extend_inputs(vec![4, 5]);
+        machine.push_input(6);
+
+        assert_eq!(machine.run(), Ok(vec![4, 5, 6]));
+    }
+
+    #[test]
+    fn input_can_be_pushed_between_waits() {
+        let mut machine = Interpreter::new(ECHO_THREE.to_vec(), vec![]);
+
+        assert_eq!(machine.run_until_input(), StepResult::NeedsInput);
+        assert_eq!(machine.position, 0);
+
+        machine.push_input(7);
+        assert_eq!(machine.run_until_input(), StepResult::NeedsInput);
+        assert_eq!(machine.position, 2);
+
+        machine.extend_inputs(vec![8, 9]);
+        machine.push_input(10);
+        assert_eq!(machine.run_until_input(), StepResult::Halted);
+
+        assert_eq!(machine.outputs(), &[7, 8, 9]);
+        assert_eq!(machine.input.pending(), Some(&vec![10].into()));
+    }
+
+    #[test]
+    #[should_panic(expected = "does not take queued input")]
+    fn pushing_onto_a_source_without_a_queue_panics() {
+        let mut machine = Interpreter::new(ECHO_THREE.to_vec(), vec![]);
+        machine.set_input(FnInput(|| Some(1)));
+
+        machine.push_input(2);
+    }
+}
@@ -0,0 +1,26 @@
+mod coverage;
+mod explore;
+mod input;
+mod interpreter;
+mod output;
+pub mod lockstep;
+mod pool;
+mod profile;
+mod program;
+
+pub use coverage::Coverage;
+pub use explore::Branch;
+pub use input::{CloneInputSource, ConstInput, FnInput, InputSource, VecInput};
+pub use interpreter::{take_panicked_state, Interpreter, Level, MachineState, Mode, OpCode, StepResult, Trap};
+pub use output::{ChannelOutput, CloneOutputSink, FnOutput, OutputSink, VecOutput};
+pub use pool::{MachinePool, PoolStats};
+pub use profile::Profile;
+pub use program::{Instructions, Program};
+
+// Comma separated codes, a trailing comma and surrounding whitespace allowed
+pub fn parse(input: &str) -> Vec<i64> {
+    input.trim().split_terminator(',')
+        .map(|x| x.trim())
+        .map(|x| x.parse::<i64>().unwrap())
+        .collect::<Vec<i64>>()
+}
@@ -0,0 +1,243 @@
+use std::fmt;
+
+use crate::{Interpreter, OpCode, StepResult};
+
+// What lockstep comparison needs from an engine. Implemented by Interpreter,
+// other backends only have to implement this to be compared against it.
+pub trait Engine {
+    fn pc(&self) -> i64;
+
+    // Why the engine stopped, `Halted` or a `Trap`, `None` while it runs
+    fn stopped(&self) -> Option<StepResult>;
+
+    fn is_awaiting_input(&self) -> bool;
+
+    fn instruction(&self) -> OpCode;
+
+    // The address the current instruction is about to write to, if any
+    fn write_target(&self) -> Option<i64>;
+
+    fn read(&self, addr: i64) -> i64;
+
+    fn step(&mut self) -> StepResult;
+}
+
+impl Engine for Interpreter {
+    fn pc(&self) -> i64 {
+        self.position
+    }
+
+    fn stopped(&self) -> Option<StepResult> {
+        match self.trap() {
+            Some(trap) => Some(StepResult::Trap(trap)),
+            None if !self.is_running() => Some(StepResult::Halted),
+            None => None
+        }
+    }
+
+    fn is_awaiting_input(&self) -> bool {
+        Interpreter::is_awaiting_input(self)
+    }
+
+    fn instruction(&self) -> OpCode {
+        self.parse_opcode(self.fetch(self.position))
+    }
+
+    fn write_target(&self) -> Option<i64> {
+        match self.instruction() {
+            OpCode::Add(_, _, m) |
+            OpCode::Multiply(_, _, m) |
+            OpCode::LessThan(_, _, m) |
+            OpCode::Equals(_, _, m) => self.write_address(self.position + 3, &m).ok(),
+            OpCode::Input(m) => self.write_address(self.position + 1, &m).ok(),
+            _ => None
+        }
+    }
+
+    fn read(&self, addr: i64) -> i64 {
+        self.fetch(addr)
+    }
+
+    fn step(&mut self) -> StepResult {
+        Interpreter::step(self)
+    }
+}
+
+// The first point at which two machines stopped doing the same thing
+#[derive(Debug, Clone, PartialEq)]
+pub enum Divergence {
+    // Both ran until they stopped the same way or waited for input without
+    // differing
+    None { steps: usize },
+    Pc { step: usize, a: i64, b: i64 },
+    Instruction { step: usize, pc: i64, a: OpCode, b: OpCode },
+    // The same instruction output different values, or only one of them
+    // output, waited for input, halted or trapped
+    Outcome { step: usize, pc: i64, instruction: OpCode, a: StepResult, b: StepResult },
+    Write { step: usize, pc: i64, instruction: OpCode, a: Option<(i64, i64)>, b: Option<(i64, i64)> },
+    // Only one of them has stopped, or they stopped for different reasons.
+    // `None` for one still running.
+    Stopped { step: usize, pc: i64, a: Option<StepResult>, b: Option<StepResult> }
+}
+
+// Steps both machines together, comparing the program counter, the decoded
+// instruction, what the step came to and what it wrote after every step
+pub fn run<A: Engine, B: Engine>(a: &mut A, b: &mut B) -> Divergence {
+    let mut step = 0;
+
+    loop {
+        if a.stopped() != b.stopped() {
+            return Divergence::Stopped { step, pc: a.pc(), a: a.stopped(), b: b.stopped() };
+        }
+
+        if a.stopped().is_some() || (a.is_awaiting_input() && b.is_awaiting_input()) {
+            return Divergence::None { steps: step };
+        }
+
+        if a.pc() != b.pc() {
+            return Divergence::Pc { step, a: a.pc(), b: b.pc() };
+        }
+
+        let pc = a.pc();
+        let instruction = a.instruction();
+
+        if instruction != b.instruction() {
+            return Divergence::Instruction { step, pc, a: instruction, b: b.instruction() };
+        }
+
+        let (a_target, b_target) = (a.write_target(), b.write_target());
+
+        let (a_outcome, b_outcome) = (a.step(), b.step());
+
+        if a_outcome != b_outcome {
+            return Divergence::Outcome { step, pc, instruction, a: a_outcome, b: b_outcome };
+        }
+
+        let a_write = a_target.map(|addr| (addr, a.read(addr)));
+        let b_write = b_target.map(|addr| (addr, b.read(addr)));
+
+        if a_write != b_write {
+            return Divergence::Write { step, pc, instruction, a: a_write, b: b_write };
+        }
+
+        step += 1;
+    }
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Divergence::None { steps } =>
+                write!(f, "no divergence in {} steps", steps),
+            Divergence::Pc { step, a, b } =>
+                write!(f, "step {}: program counters differ, a is at {} and b at {}", step, a, b),
+            Divergence::Instruction { step, pc, a, b } =>
+                write!(f, "step {} at {}: a decoded {:?} but b decoded {:?}", step, pc, a, b),
+            Divergence::Outcome { step, pc, instruction, a, b } =>
+                write!(f, "step {} at {} ({:?}): a {} but b {}", step, pc, instruction, outcome(a), outcome(b)),
+            Divergence::Write { step, pc, instruction, a, b } =>
+                write!(f, "step {} at {} ({:?}): a wrote {:?} but b wrote {:?} (address, value)", step, pc, instruction, a, b),
+            Divergence::Stopped { step, pc, a, b } =>
+                write!(f, "step {} at {}: a {} but b {}", step, pc, stopped(a), stopped(b))
+        }
+    }
+}
+
+fn outcome(result: &StepResult) -> String {
+    match result {
+        StepResult::Continue => "carried on".to_string(),
+        StepResult::Output(value) => format!("output {}", value),
+        StepResult::NeedsInput => "waited for input".to_string(),
+        StepResult::Halted => "halted".to_string(),
+        StepResult::Trap(trap) => format!("trapped ({})", trap)
+    }
+}
+
+fn stopped(result: &Option<StepResult>) -> String {
+    match result {
+        Some(result) => outcome(result),
+        None => "is still running".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Mode, Trap};
+
+    fn compare(a: Vec<i64>, b: Vec<i64>) -> Divergence {
+        run(&mut Interpreter::new(a, vec![]), &mut Interpreter::new(b, vec![]))
+    }
+
+    #[test]
+    fn identical_programs_do_not_diverge() {
+        assert_eq!(compare(vec![4, 3, 99, 7], vec![4, 3, 99, 7]), Divergence::None { steps: 2 });
+    }
+
+    #[test]
+    fn different_outputs_diverge() {
+        let divergence = compare(vec![4, 3, 99, 7], vec![4, 3, 99, 8]);
+
+        assert_eq!(divergence, Divergence::Outcome {
+            step: 0,
+            pc: 0,
+            instruction: OpCode::Output(Mode::Position),
+            a: StepResult::Output(7),
+            b: StepResult::Output(8)
+        });
+        assert_eq!(divergence.to_string(), "step 0 at 0 (Output(Position)): a output 7 but b output 8");
+    }
+
+    #[test]
+    fn different_writes_diverge() {
+        let divergence = compare(vec![1101, 1, 2, 7, 99, 0, 0, 0], vec![1101, 1, 3, 7, 99, 0, 0, 0]);
+
+        assert_eq!(divergence, Divergence::Write {
+            step: 0,
+            pc: 0,
+            instruction: OpCode::Add(Mode::Immediate, Mode::Immediate, Mode::Position),
+            a: Some((7, 3)),
+            b: Some((7, 4))
+        });
+    }
+
+    #[test]
+    fn one_side_stopping_early_diverges() {
+        let program = vec![104, 1, 99];
+
+        let mut halted = Interpreter::new(program.clone(), vec![]);
+        halted.run().unwrap();
+
+        let divergence = run(&mut halted, &mut Interpreter::new(program, vec![]));
+
+        assert_eq!(divergence, Divergence::Stopped { step: 0, pc: 2, a: Some(StepResult::Halted), b: None });
+        assert_eq!(divergence.to_string(), "step 0 at 2: a halted but b is still running");
+    }
+
+    #[test]
+    fn a_trap_is_reported_as_a_trap() {
+        let program = vec![1101, 1, 2, 5, 99, 0];
+
+        let mut trapping = Interpreter::new(program.clone(), vec![]);
+        trapping.protect(5..6);
+
+        let divergence = run(&mut trapping, &mut Interpreter::new(program, vec![]));
+
+        assert_eq!(divergence, Divergence::Outcome {
+            step: 0,
+            pc: 0,
+            instruction: OpCode::Add(Mode::Immediate, Mode::Immediate, Mode::Position),
+            a: StepResult::Trap(Trap::ProtectedWrite { addr: 5 }),
+            b: StepResult::Continue
+        });
+    }
+
+    #[test]
+    fn only_one_side_waiting_for_input_diverges() {
+        let program = vec![3, 5, 99, 0, 0, 0];
+
+        let divergence = run(&mut Interpreter::new(program.clone(), vec![]), &mut Interpreter::new(program, vec![1]));
+
+        assert!(matches!(divergence, Divergence::Outcome { a: StepResult::NeedsInput, b: StepResult::Continue, .. }));
+    }
+}
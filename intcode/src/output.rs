@@ -0,0 +1,79 @@
+use std::fmt;
+use std::sync::mpsc::Sender;
+
+// Where a machine's output instructions send their values
+pub trait OutputSink: fmt::Debug + Send + CloneOutputSink {
+    fn send(&mut self, value: i64);
+
+    // Everything sent so far, for sinks that hold on to it
+    fn collected(&self) -> Option<&[i64]> {
+        None
+    }
+}
+
+// Lets machines holding a boxed sink still be cloned, implemented for every
+// sink that is Clone
+pub trait CloneOutputSink {
+    fn clone_box(&self) -> Box<dyn OutputSink>;
+}
+
+impl<T: OutputSink + Clone + 'static> CloneOutputSink for T {
+    fn clone_box(&self) -> Box<dyn OutputSink> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn OutputSink> {
+    fn clone(&self) -> Box<dyn OutputSink> {
+        self.clone_box()
+    }
+}
+
+// Keeps every value, what a machine writes to unless told otherwise
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct VecOutput {
+    values: Vec<i64>
+}
+
+impl VecOutput {
+    pub fn new(values: Vec<i64>) -> VecOutput {
+        VecOutput { values }
+    }
+}
+
+impl OutputSink for VecOutput {
+    fn send(&mut self, value: i64) {
+        self.values.push(value);
+    }
+
+    fn collected(&self) -> Option<&[i64]> {
+        Some(&self.values)
+    }
+}
+
+// Hands every value to a closure as it comes out
+#[derive(Clone)]
+pub struct FnOutput<F>(pub F);
+
+impl<F> fmt::Debug for FnOutput<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("FnOutput")
+    }
+}
+
+impl<F: FnMut(i64) + Clone + Send + 'static> OutputSink for FnOutput<F> {
+    fn send(&mut self, value: i64) {
+        (self.0)(value)
+    }
+}
+
+// Sends every value down a channel, for machines running on their own thread.
+// Values sent after the receiver is gone are dropped.
+#[derive(Debug, Clone)]
+pub struct ChannelOutput(pub Sender<i64>);
+
+impl OutputSink for ChannelOutput {
+    fn send(&mut self, value: i64) {
+        let _ = self.0.send(value);
+    }
+}
@@ -0,0 +1,125 @@
+use crate::{Interpreter, Level};
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PoolStats {
+    pub created: usize,
+    pub reused: usize,
+    pub released: usize
+}
+
+// Hands out interpreters with a program already loaded. Released machines are
+// reset in place from the template so repeated probes don't rebuild memory
+// from scratch each time.
+#[derive(Debug)]
+pub struct MachinePool {
+    template: Interpreter,
+    idle: Vec<Interpreter>,
+    stats: PoolStats
+}
+
+impl MachinePool {
+    pub fn new(codes: Vec<i64>) -> MachinePool {
+        MachinePool::with_level(codes, Level::Day9)
+    }
+
+    pub fn with_level(codes: Vec<i64>, level: Level) -> MachinePool {
+        MachinePool {
+            template: Interpreter::with_level(codes, vec![], level),
+            idle: Vec::new(),
+            stats: PoolStats::default()
+        }
+    }
+
+    pub fn acquire(&mut self) -> Interpreter {
+        match self.idle.pop() {
+            Some(mut machine) => {
+                machine.reset_from(&self.template);
+                self.stats.reused += 1;
+
+                machine
+            },
+            None => {
+                self.stats.created += 1;
+
+                self.template.clone()
+            }
+        }
+    }
+
+    pub fn release(&mut self, machine: Interpreter) {
+        self.stats.released += 1;
+        self.idle.push(machine);
+    }
+
+    pub fn idle(&self) -> usize {
+        self.idle.len()
+    }
+
+    pub fn stats(&self) -> &PoolStats {
+        &self.stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StepResult;
+
+    // Moves the relative base, then echoes its input forever
+    const ECHO: [i64; 9] = [109, 5, 3, 20, 4, 20, 1105, 1, 2];
+
+    #[test]
+    fn reacquired_machines_are_pristine_under_load() {
+        let pristine = Interpreter::new(ECHO.to_vec(), vec![]);
+        let mut pool = MachinePool::new(ECHO.to_vec());
+
+        let mut acquired = 0;
+
+        for round in 0..200 {
+            // Vary how many are out at once so some rounds need new machines
+            let machines = (0..round % 7 + 1)
+                .map(|_| pool.acquire())
+                .collect::<Vec<Interpreter>>();
+
+            acquired += machines.len();
+
+            for (i, mut machine) in machines.into_iter().enumerate() {
+                assert_eq!(machine, pristine);
+                assert_eq!(machine.codes, pristine.codes);
+                assert_eq!(machine.position, 0);
+                assert!(machine.outputs().is_empty());
+                assert_eq!(machine.run_until_input(), StepResult::NeedsInput);
+
+                // Leave it mid-run with output collected, memory written and
+                // input still queued, or trapped on a protected write
+                machine.extend_inputs(vec![round as i64; i + 2]);
+                assert_eq!(machine.run_until_output(), Ok(Some(round as i64)));
+
+                if i % 3 == 2 {
+                    machine.protect(20..21);
+                    machine.run_until_input();
+                    assert!(machine.trap().is_some());
+                }
+
+                assert_ne!(machine, pristine);
+
+                pool.release(machine);
+            }
+        }
+
+        let stats = pool.stats();
+
+        assert_eq!(stats.created, 7);
+        assert_eq!(stats.reused, acquired - 7);
+        assert_eq!(stats.released, acquired);
+        assert_eq!(pool.idle(), 7);
+
+        let mut machine = pool.acquire();
+
+        assert_eq!(machine, pristine);
+        assert_eq!(machine.trap(), None);
+        assert!(!machine.is_awaiting_input());
+        assert_eq!(machine.run_until_input(), StepResult::NeedsInput);
+        assert!(machine.is_awaiting_input());
+    }
+}
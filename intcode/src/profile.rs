@@ -0,0 +1,192 @@
+use std::collections::BTreeMap;
+
+use serde_json::json;
+
+use crate::program::mnemonic;
+use crate::{Interpreter, Mode, OpCode, StepResult};
+
+// How a program behaves when run: which instructions and operand modes it
+// leans on, where its taken jumps land and which memory it writes to
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Profile {
+    pub steps: usize,
+    pub program_len: usize,
+    // Cells in use when the run ended, the program included
+    pub memory_cells: usize,
+    pub opcodes: BTreeMap<&'static str, usize>,
+    pub modes: BTreeMap<&'static str, usize>,
+    pub jump_targets: BTreeMap<i64, usize>,
+    pub writes: BTreeMap<i64, usize>
+}
+
+impl Interpreter {
+    // Runs the machine until it halts, traps, waits for input or has taken
+    // `max_steps` steps, recording what each instruction did
+    pub fn profile(&mut self, max_steps: usize) -> Profile {
+        let mut profile = Profile {
+            program_len: self.codes.len(),
+            ..Profile::default()
+        };
+
+        while self.is_running() && profile.steps < max_steps && !self.is_awaiting_input() {
+            let start = self.position;
+            let op = self.parse_opcode(self.fetch(start));
+
+            let written = match &op {
+                OpCode::Add(_, _, m) |
+                OpCode::Multiply(_, _, m) |
+                OpCode::LessThan(_, _, m) |
+                OpCode::Equals(_, _, m) => self.write_address(start + 3, m).ok(),
+                OpCode::Input(m) => self.write_address(start + 1, m).ok(),
+                _ => None
+            };
+
+            // A trapped instruction didn't run, so there's nothing to record
+            if let StepResult::Trap(_) = self.step() {
+                break;
+            }
+
+            profile.steps += 1;
+
+            *profile.opcodes.entry(mnemonic(&op)).or_insert(0) += 1;
+
+            for mode in op.modes() {
+                *profile.modes.entry(mode_name(mode)).or_insert(0) += 1;
+            }
+
+            if let Some(addr) = written {
+                *profile.writes.entry(addr).or_insert(0) += 1;
+            }
+
+            if let OpCode::JumpIfTrue(_, _) | OpCode::JumpIfFalse(_, _) = op {
+                if self.position != start + 3 {
+                    *profile.jump_targets.entry(self.position).or_insert(0) += 1;
+                }
+            }
+        }
+
+        profile.memory_cells = self.codes.len();
+
+        profile
+    }
+}
+
+impl Profile {
+    pub fn to_json(&self) -> serde_json::Value {
+        let total_writes = self.writes.values().sum::<usize>();
+        let outside_program = self.writes.iter()
+            .filter(|(&addr, _)| addr >= self.program_len as i64)
+            .map(|(_, &count)| count)
+            .sum::<usize>();
+
+        json!({
+            "steps": self.steps,
+            "memory_cells": self.memory_cells,
+            "opcodes": self.opcodes,
+            "modes": self.modes,
+            "jumps": {
+                "taken": self.jump_targets.values().sum::<usize>(),
+                "targets": hottest(&self.jump_targets, 10)
+            },
+            "writes": {
+                "total": total_writes,
+                "distinct_addresses": self.writes.len(),
+                "outside_program": outside_program,
+                "hottest": hottest(&self.writes, 10)
+            }
+        })
+    }
+}
+
+fn mode_name(mode: &Mode) -> &'static str {
+    match mode {
+        Mode::Position => "position",
+        Mode::Immediate => "immediate",
+        Mode::Relative => "relative"
+    }
+}
+
+// The `n` most common addresses, most frequent first
+fn hottest(counts: &BTreeMap<i64, usize>, n: usize) -> Vec<serde_json::Value> {
+    let mut counts = counts.iter()
+        .map(|(&addr, &count)| (addr, count))
+        .collect::<Vec<_>>();
+
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+
+    counts.into_iter()
+        .take(n)
+        .map(|(address, count)| json!({ "address": address, "count": count }))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Counts cell 12 up to 3, jumping back to the start while it's below
+    const LOOP: [i64; 14] = [1001, 12, 1, 12, 1007, 12, 3, 13, 1005, 13, 0, 99, 0, 0];
+
+    fn counts(pairs: &[(&'static str, usize)]) -> BTreeMap<&'static str, usize> {
+        pairs.iter().copied().collect()
+    }
+
+    #[test]
+    fn counts_opcodes_and_modes() {
+        let mut machine = Interpreter::new(vec![1101, 2, 3, 11, 1002, 11, 2, 11, 4, 11, 99, 0], vec![]);
+        let profile = machine.profile(100);
+
+        assert_eq!(profile.steps, 4);
+        assert_eq!(profile.opcodes, counts(&[("add", 1), ("mul", 1), ("out", 1), ("hlt", 1)]));
+        assert_eq!(profile.modes, counts(&[("position", 4), ("immediate", 3)]));
+        assert_eq!(profile.writes, vec![(11, 2)].into_iter().collect());
+        assert!(profile.jump_targets.is_empty());
+    }
+
+    #[test]
+    fn counts_every_pass_through_a_loop() {
+        let profile = Interpreter::new(LOOP.to_vec(), vec![]).profile(100);
+
+        assert_eq!(profile.steps, 10);
+        assert_eq!(profile.opcodes, counts(&[("add", 3), ("lt", 3), ("jnz", 3), ("hlt", 1)]));
+        assert_eq!(profile.writes, vec![(12, 3), (13, 3)].into_iter().collect());
+
+        // The last jnz falls through to the halt, it isn't taken
+        assert_eq!(profile.jump_targets, vec![(0, 2)].into_iter().collect());
+    }
+
+    #[test]
+    fn stops_at_the_step_limit_and_at_input() {
+        assert_eq!(Interpreter::new(LOOP.to_vec(), vec![]).profile(4).steps, 4);
+
+        let profile = Interpreter::new(vec![104, 1, 3, 0, 99], vec![]).profile(100);
+
+        assert_eq!(profile.steps, 1);
+        assert_eq!(profile.opcodes, counts(&[("out", 1)]));
+    }
+
+    #[test]
+    fn leaves_out_a_trapped_instruction() {
+        let mut machine = Interpreter::new(vec![104, 1, 1101, 1, 1, 10, 99], vec![]);
+        machine.protect(10..11);
+
+        let profile = machine.profile(100);
+
+        assert_eq!(profile.steps, 1);
+        assert_eq!(profile.opcodes, counts(&[("out", 1)]));
+        assert!(profile.writes.is_empty());
+    }
+
+    #[test]
+    fn reports_writes_outside_the_program() {
+        let profile = Interpreter::new(vec![1101, 1, 1, 20, 1101, 1, 1, 20, 1101, 1, 1, 3, 99], vec![]).profile(100);
+        let json = profile.to_json();
+
+        assert_eq!(profile.program_len, 13);
+        assert!(profile.memory_cells > profile.program_len);
+        assert_eq!(json["writes"]["total"], 3);
+        assert_eq!(json["writes"]["distinct_addresses"], 2);
+        assert_eq!(json["writes"]["outside_program"], 2);
+        assert_eq!(json["writes"]["hottest"][0], json!({ "address": 20, "count": 2 }));
+    }
+}
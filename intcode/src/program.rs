@@ -0,0 +1,289 @@
+use std::fmt::Write;
+
+use crate::{Mode, OpCode};
+
+// A program image to look at without running it
+#[derive(Debug, Clone, PartialEq)]
+pub struct Program {
+    codes: Vec<i64>
+}
+
+impl Program {
+    pub fn new(codes: Vec<i64>) -> Program {
+        Program { codes }
+    }
+
+    pub fn parse(input: &str) -> Program {
+        Program::new(crate::parse(input))
+    }
+
+    pub fn codes(&self) -> &[i64] {
+        &self.codes
+    }
+
+    pub fn len(&self) -> usize {
+        self.codes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.codes.is_empty()
+    }
+
+    // Walks the image from address 0 decoding one instruction after the
+    // other, see `Instructions` for where that stops
+    pub fn instructions(&self) -> Instructions<'_> {
+        Instructions { codes: &self.codes, addr: 0, data_start: None }
+    }
+
+    // The decodable part as a disassembly, followed by the rest of the image
+    // as raw cells
+    pub fn listing(&self) -> String {
+        let mut listing = String::new();
+        let mut instructions = self.instructions();
+
+        for (addr, op, params) in instructions.by_ref() {
+            writeln!(listing, "{:05}  {}", addr, disassemble(&op, &params)).unwrap();
+        }
+
+        if let Some(start) = instructions.data_start() {
+            writeln!(listing, "; decoding stops here, the rest may be code or data").unwrap();
+
+            for (addr, value) in self.codes.iter().enumerate().skip(start as usize) {
+                writeln!(listing, "{:05}  {}", addr, value).unwrap();
+            }
+        }
+
+        listing
+    }
+}
+
+impl<'a> IntoIterator for &'a Program {
+    type Item = (i64, OpCode, Vec<i64>);
+    type IntoIter = Instructions<'a>;
+
+    fn into_iter(self) -> Instructions<'a> {
+        self.instructions()
+    }
+}
+
+// Instructions laid out back to back from address 0, as `(addr, op, raw
+// parameters)`. Nothing runs, so jumps aren't followed and the walk ends at
+// the first cell that isn't a valid instruction. From there on the image
+// can't be told apart from data, `data_start` says where that was.
+#[derive(Debug, Clone)]
+pub struct Instructions<'a> {
+    codes: &'a [i64],
+    addr: i64,
+    data_start: Option<i64>
+}
+
+impl Instructions<'_> {
+    // Where decoding failed, `None` while still decoding or when the whole
+    // image decoded cleanly
+    pub fn data_start(&self) -> Option<i64> {
+        self.data_start
+    }
+}
+
+impl Iterator for Instructions<'_> {
+    type Item = (i64, OpCode, Vec<i64>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let addr = self.addr;
+
+        if self.data_start.is_some() || addr as usize >= self.codes.len() {
+            return None;
+        }
+
+        let decoded = decode(self.codes[addr as usize])
+            .and_then(|op| {
+                let params = self.codes.get(addr as usize + 1..(addr + op.width()) as usize)?;
+
+                Some((op, params.to_vec()))
+            });
+
+        match decoded {
+            Some((op, params)) => {
+                self.addr += op.width();
+
+                Some((addr, op, params))
+            },
+            None => {
+                self.data_start = Some(addr);
+
+                None
+            }
+        }
+    }
+}
+
+// Stricter than the interpreter, which takes unknown modes as position mode
+// and 0 as a no-op. Neither shows up in real code, so here they mean data.
+fn decode(raw: i64) -> Option<OpCode> {
+    if !(0..100_000).contains(&raw) {
+        return None;
+    }
+
+    let mode = |digit: i64| match (raw / digit) % 10 {
+        0 => Some(Mode::Position),
+        1 => Some(Mode::Immediate),
+        2 => Some(Mode::Relative),
+        _ => None
+    };
+
+    let (a, b, c) = (mode(100)?, mode(1_000)?, mode(10_000)?);
+
+    let op = match raw % 100 {
+        1 => OpCode::Add(a, b, c),
+        2 => OpCode::Multiply(a, b, c),
+        3 => OpCode::Input(a),
+        4 => OpCode::Output(a),
+        5 => OpCode::JumpIfTrue(a, b),
+        6 => OpCode::JumpIfFalse(a, b),
+        7 => OpCode::LessThan(a, b, c),
+        8 => OpCode::Equals(a, b, c),
+        9 => OpCode::AdjustBase(a),
+        99 => OpCode::Halt,
+        _ => return None
+    };
+
+    // Modes past the last parameter have to be left at zero
+    if raw / 100 >= 10i64.pow(op.width() as u32 - 1) {
+        return None;
+    }
+
+    let writes_immediate = match &op {
+        OpCode::Add(_, _, c) |
+        OpCode::Multiply(_, _, c) |
+        OpCode::LessThan(_, _, c) |
+        OpCode::Equals(_, _, c) => *c == Mode::Immediate,
+        OpCode::Input(a) => *a == Mode::Immediate,
+        _ => false
+    };
+
+    if writes_immediate {
+        None
+    } else {
+        Some(op)
+    }
+}
+
+pub(crate) fn mnemonic(op: &OpCode) -> &'static str {
+    match op {
+        OpCode::Add(..) => "add",
+        OpCode::Multiply(..) => "mul",
+        OpCode::Halt => "hlt",
+        OpCode::Input(_) => "in",
+        OpCode::Output(_) => "out",
+        OpCode::JumpIfTrue(..) => "jnz",
+        OpCode::JumpIfFalse(..) => "jz",
+        OpCode::LessThan(..) => "lt",
+        OpCode::Equals(..) => "eq",
+        OpCode::AdjustBase(_) => "arb",
+        OpCode::Noop => "nop"
+    }
+}
+
+pub(crate) fn disassemble(op: &OpCode, operands: &[i64]) -> String {
+    let name = mnemonic(op);
+
+    let operands = op.modes()
+        .into_iter()
+        .zip(operands)
+        .map(|(mode, value)| match mode {
+            Mode::Position => format!("[{}]", value),
+            Mode::Immediate => value.to_string(),
+            Mode::Relative => format!("[rb{:+}]", value)
+        })
+        .collect::<Vec<String>>();
+
+    if operands.is_empty() {
+        name.to_string()
+    } else {
+        format!("{} {}", name, operands.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_valid_modes() {
+        assert_eq!(decode(1002), Some(OpCode::Multiply(Mode::Position, Mode::Immediate, Mode::Position)));
+        assert_eq!(decode(21201), Some(OpCode::Add(Mode::Relative, Mode::Immediate, Mode::Relative)));
+        assert_eq!(decode(99), Some(OpCode::Halt));
+    }
+
+    #[test]
+    fn invalid_mode_digits_are_data() {
+        assert_eq!(decode(301), None);
+        assert_eq!(decode(1901), None);
+        assert_eq!(decode(90001), None);
+
+        // Modes for parameters the instruction doesn't have
+        assert_eq!(decode(199), None);
+        assert_eq!(decode(1004), None);
+    }
+
+    #[test]
+    fn immediate_mode_writes_are_data() {
+        assert_eq!(decode(11101), None);
+        assert_eq!(decode(11102), None);
+        assert_eq!(decode(10007), None);
+        assert_eq!(decode(10008), None);
+        assert_eq!(decode(103), None);
+
+        // Reads can still be immediate
+        assert_eq!(decode(104), Some(OpCode::Output(Mode::Immediate)));
+    }
+
+    #[test]
+    fn out_of_range_and_unknown_opcodes_are_data() {
+        assert_eq!(decode(-1), None);
+        assert_eq!(decode(100_001), None);
+        assert_eq!(decode(0), None);
+        assert_eq!(decode(42), None);
+    }
+
+    #[test]
+    fn walks_instructions_back_to_back() {
+        let program = Program::new(vec![1101, 1, 2, 7, 104, 5, 99]);
+        let mut instructions = program.instructions();
+
+        assert_eq!(instructions.by_ref().collect::<Vec<_>>(), vec![
+            (0, OpCode::Add(Mode::Immediate, Mode::Immediate, Mode::Position), vec![1, 2, 7]),
+            (4, OpCode::Output(Mode::Immediate), vec![5]),
+            (6, OpCode::Halt, vec![])
+        ]);
+        assert_eq!(instructions.data_start(), None);
+    }
+
+    #[test]
+    fn data_starts_after_the_last_decodable_instruction() {
+        let program = Program::new(vec![1101, 1, 2, 7, 99, -1, 1101, 0, 0, 0]);
+        let mut instructions = program.instructions();
+
+        assert_eq!(instructions.by_ref().map(|(addr, _, _)| addr).collect::<Vec<_>>(), vec![0, 4]);
+        assert_eq!(instructions.data_start(), Some(5));
+
+        // Decoding doesn't pick up again after the data
+        assert_eq!(instructions.next(), None);
+    }
+
+    #[test]
+    fn a_truncated_instruction_starts_the_data() {
+        let program = Program::new(vec![104, 1, 1101, 1]);
+        let mut instructions = program.instructions();
+
+        assert_eq!(instructions.by_ref().count(), 1);
+        assert_eq!(instructions.data_start(), Some(2));
+    }
+
+    #[test]
+    fn listing_marks_where_decoding_stops() {
+        let listing = Program::new(vec![104, 1, 99, 7]).listing();
+
+        assert_eq!(listing, "00000  out 1\n00002  hlt\n; decoding stops here, the rest may be code or data\n00003  7\n");
+    }
+}
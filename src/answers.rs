@@ -0,0 +1,216 @@
+use std::fs;
+use std::path::Path;
+
+use harness::{Day, Part};
+use serde::{Deserialize, Serialize};
+
+// Known-correct answers, kept in answers.toml so every run doubles as a
+// regression check
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(default, rename = "day")]
+    days: Vec<Known>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Known {
+    day: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part2: Option<String>,
+    #[serde(default, skip_serializing_if = "Comparator::is_exact")]
+    part1_compare: Comparator,
+    #[serde(default, skip_serializing_if = "Comparator::is_exact")]
+    part2_compare: Comparator
+}
+
+// How an answer is held against the known one, set per part in answers.toml
+// as `part2_compare = "normalized-grid"` or
+// `part1_compare = { numeric = { tolerance = 0.001 } }`
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Comparator {
+    #[default]
+    Exact,
+    // Rendered grids, where trailing whitespace on a line and blank lines
+    // around the picture don't count
+    NormalizedGrid,
+    Numeric { tolerance: f64 }
+}
+
+impl Comparator {
+    fn is_exact(&self) -> bool {
+        *self == Comparator::Exact
+    }
+
+    pub fn matches(&self, expected: &str, answer: &str) -> bool {
+        match self {
+            Comparator::Exact => expected == answer,
+            Comparator::NormalizedGrid => normalize_grid(expected) == normalize_grid(answer),
+            Comparator::Numeric { tolerance } => match (expected.trim().parse::<f64>(), answer.trim().parse::<f64>()) {
+                (Ok(expected), Ok(answer)) => (expected - answer).abs() <= *tolerance,
+                _ => false
+            }
+        }
+    }
+}
+
+fn normalize_grid(grid: &str) -> Vec<&str> {
+    let lines = grid.lines().map(str::trim_end).collect::<Vec<&str>>();
+
+    let start = lines.iter().position(|l| !l.is_empty()).unwrap_or(lines.len());
+    let end = lines.iter().rposition(|l| !l.is_empty()).map_or(start, |i| i + 1);
+
+    lines[start..end].to_vec()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Check {
+    Match,
+    Regression(String),
+    Unknown
+}
+
+impl Answers {
+    // A missing file is the same as an empty one
+    pub fn load(path: &Path) -> Result<Answers, String> {
+        if !path.exists() {
+            return Ok(Answers::default());
+        }
+
+        let contents = fs::read_to_string(path)
+            .map_err(|e| format!("Could not read {}: {}", path.display(), e))?;
+
+        toml::from_str(&contents).map_err(|e| format!("Could not parse {}: {}", path.display(), e))
+    }
+
+    pub fn save(&mut self, path: &Path) -> Result<(), String> {
+        self.days.sort_by_key(|k| k.day);
+
+        let contents = toml::to_string(self).map_err(|e| e.to_string())?;
+
+        fs::write(path, contents).map_err(|e| format!("Could not write {}: {}", path.display(), e))
+    }
+
+    pub fn get(&self, day: Day, part: Part) -> Option<&str> {
+        let known = self.days.iter().find(|k| k.day == day.get())?;
+
+        match part {
+            Part::One => known.part1.as_deref(),
+            Part::Two => known.part2.as_deref()
+        }
+    }
+
+    pub fn set(&mut self, day: Day, part: Part, answer: &str) {
+        let index = match self.days.iter().position(|k| k.day == day.get()) {
+            Some(index) => index,
+            None => {
+                self.days.push(Known {
+                    day: day.get(),
+                    part1: None,
+                    part2: None,
+                    part1_compare: Comparator::Exact,
+                    part2_compare: Comparator::Exact
+                });
+                self.days.len() - 1
+            }
+        };
+
+        let known = &mut self.days[index];
+
+        match part {
+            Part::One => known.part1 = Some(answer.to_string()),
+            Part::Two => known.part2 = Some(answer.to_string())
+        }
+    }
+
+    pub fn comparator(&self, day: Day, part: Part) -> Comparator {
+        let known = self.days.iter().find(|k| k.day == day.get());
+
+        match (known, part) {
+            (Some(known), Part::One) => known.part1_compare.clone(),
+            (Some(known), Part::Two) => known.part2_compare.clone(),
+            (None, _) => Comparator::Exact
+        }
+    }
+
+    pub fn check(&self, day: Day, part: Part, answer: &str) -> Check {
+        match self.get(day, part) {
+            Some(expected) if self.comparator(day, part).matches(expected, answer) => Check::Match,
+            Some(expected) => Check::Regression(expected.to_string()),
+            None => Check::Unknown
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(toml: &str) -> Result<Answers, String> {
+        toml::from_str(toml).map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn normalized_grids_ignore_trailing_whitespace_and_blank_lines() {
+        let expected = "#..#\n#..#";
+
+        assert!(Comparator::NormalizedGrid.matches(expected, "\n#..#   \n#..#\t\n\n"));
+        assert!(Comparator::NormalizedGrid.matches(expected, "#..#\r\n#..#\r\n"));
+        assert_eq!(normalize_grid("\n\n  \n"), Vec::<&str>::new());
+
+        // Leading whitespace is part of the picture
+        assert!(!Comparator::NormalizedGrid.matches(expected, " #..#\n#..#"));
+        assert!(!Comparator::Exact.matches(expected, "#..#\n#..#\n"));
+    }
+
+    #[test]
+    fn numeric_answers_compare_as_numbers() {
+        let numeric = Comparator::Numeric { tolerance: 0.001 };
+
+        assert!(numeric.matches("1.5", " 1.5004\n"));
+        assert!(numeric.matches("42", "42.0"));
+        assert!(!numeric.matches("1.5", "1.502"));
+        assert!(!numeric.matches("42", "forty-two"));
+
+        // Exactly, those are different strings
+        assert!(!Comparator::Exact.matches("42", "42.0"));
+    }
+
+    #[test]
+    fn reads_comparators_per_part() {
+        let answers = parse(r##"
+            [[day]]
+            day = 8
+            part1 = "1965"
+            part2 = "#..#"
+            part2_compare = "normalized-grid"
+
+            [[day]]
+            day = 12
+            part1_compare = { numeric = { tolerance = 0.5 } }
+        "##).unwrap();
+
+        assert_eq!(answers.comparator(Day::new(8), Part::One), Comparator::Exact);
+        assert_eq!(answers.comparator(Day::new(8), Part::Two), Comparator::NormalizedGrid);
+        assert_eq!(answers.comparator(Day::new(12), Part::One), Comparator::Numeric { tolerance: 0.5 });
+        assert_eq!(answers.comparator(Day::new(3), Part::One), Comparator::Exact);
+
+        assert_eq!(answers.check(Day::new(8), Part::Two, "#..#  \n"), Check::Match);
+        assert_eq!(answers.check(Day::new(8), Part::One, "1966"), Check::Regression("1965".to_string()));
+        assert_eq!(answers.check(Day::new(12), Part::One, "7"), Check::Unknown);
+    }
+
+    #[test]
+    fn an_unknown_comparator_is_an_error() {
+        let error = parse(r#"
+            [[day]]
+            day = 1
+            part1 = "5"
+            part1_compare = "fuzzy"
+        "#).unwrap_err();
+
+        assert!(error.contains("unknown variant `fuzzy`"), "{}", error);
+    }
+}
@@ -0,0 +1,78 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use harness::{Day, Part};
+use serde_json::json;
+
+pub struct Timings {
+    pub day: Day,
+    pub part: Part,
+    pub answer: String,
+    runs: Vec<Duration>
+}
+
+// Solves each part `warmup` times without timing it, then `runs` more times
+// timing each one, with `engine` where the part has it
+pub fn run(day: Day, parts: &[Part], input: &str, runs: u32, warmup: u32, engine: Option<&str>) -> Vec<Timings> {
+    parts.iter()
+        .map(|&part| {
+            for _ in 0..warmup {
+                aoc2019::solve_with(day, part, input, engine, &mut None);
+            }
+
+            let mut answer = String::new();
+
+            let mut runs = (0..runs)
+                .map(|_| {
+                    let start = Instant::now();
+                    answer = aoc2019::solve_with(day, part, input, engine, &mut None);
+
+                    start.elapsed()
+                })
+                .collect::<Vec<Duration>>();
+
+            runs.sort();
+
+            Timings { day, part, answer, runs }
+        })
+        .collect()
+}
+
+impl Timings {
+    pub fn min(&self) -> Duration {
+        self.runs[0]
+    }
+
+    pub fn median(&self) -> Duration {
+        let mid = self.runs.len() / 2;
+
+        if self.runs.len().is_multiple_of(2) {
+            (self.runs[mid - 1] + self.runs[mid]) / 2
+        } else {
+            self.runs[mid]
+        }
+    }
+
+    pub fn mean(&self) -> Duration {
+        self.runs.iter().sum::<Duration>() / self.runs.len() as u32
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        json!({
+            "day": self.day.get(),
+            "part": self.part.number(),
+            "answer": self.answer,
+            "runs": self.runs.len(),
+            "min_micros": self.min().as_micros() as u64,
+            "median_micros": self.median().as_micros() as u64,
+            "mean_micros": self.mean().as_micros() as u64
+        })
+    }
+}
+
+impl fmt::Display for Timings {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Day {} part {}: {} (min {:.2?}, median {:.2?}, mean {:.2?} over {} runs)",
+            self.day, self.part, self.answer, self.min(), self.median(), self.mean(), self.runs.len())
+    }
+}
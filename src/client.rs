@@ -0,0 +1,112 @@
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use ureq::http::Response;
+use ureq::Body;
+
+const USER_AGENT: &str = "github.com/jos-b/aoc-2019";
+
+// Every request to adventofcode.com goes through here, so the site never sees
+// more than one request per MIN_INTERVAL from us however the CLI is driven
+const MIN_INTERVAL: Duration = Duration::from_secs(3);
+const RETRIES: u32 = 3;
+const FIRST_BACKOFF: Duration = Duration::from_secs(2);
+
+// Held for the whole request so parallel days queue up behind each other
+static THROTTLE: Mutex<()> = Mutex::new(());
+
+pub fn get(url: &str, session: Option<&str>) -> Result<String, String> {
+    send(url, || {
+        let request = ureq::get(url).header("User-Agent", USER_AGENT);
+
+        match session {
+            Some(session) => request.header("Cookie", &format!("session={}", session)).call(),
+            None => request.call()
+        }
+    })
+    .map_err(|e| format!("Could not fetch {}: {}", url, e))
+}
+
+pub fn post_form(url: &str, session: &str, form: &[(&str, String)]) -> Result<String, String> {
+    send(url, || {
+        ureq::post(url)
+            .header("User-Agent", USER_AGENT)
+            .header("Cookie", &format!("session={}", session))
+            .send_form(form.iter().map(|(k, v)| (*k, v.as_str())))
+    })
+    .map_err(|e| format!("Could not post to {}: {}", url, e))
+}
+
+// Makes the request, retrying failures that might go away by themselves with
+// a doubling wait in between
+fn send(url: &str, request: impl Fn() -> Result<Response<Body>, ureq::Error>) -> Result<String, String> {
+    let mut backoff = FIRST_BACKOFF;
+    let mut attempt = 0;
+
+    loop {
+        let result = {
+            let _turn = wait_turn();
+            request()
+        };
+
+        match result {
+            Ok(mut response) => {
+                return response.body_mut()
+                    .read_to_string()
+                    .map_err(|e| format!("could not read the response from {}: {}", url, e));
+            },
+            Err(e) if attempt < RETRIES && is_transient(&e) => {
+                attempt += 1;
+                thread::sleep(backoff);
+                backoff *= 2;
+            },
+            Err(e) => return Err(e.to_string())
+        }
+    }
+}
+
+// Server trouble, rate limiting and timeouts. A missing network or a failed
+// lookup fails straight away so the offline cache is used without waiting.
+fn is_transient(e: &ureq::Error) -> bool {
+    match e {
+        ureq::Error::StatusCode(code) => *code == 429 || *code >= 500,
+        ureq::Error::Io(e) => e.kind() == io::ErrorKind::TimedOut,
+        ureq::Error::Timeout(_) => true,
+        _ => false
+    }
+}
+
+// Blocks until MIN_INTERVAL has passed since the last request, from this run
+// or an earlier one, and claims the next slot
+fn wait_turn() -> std::sync::MutexGuard<'static, ()> {
+    let turn = THROTTLE.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    let path = last_request_path();
+
+    let last = fs::read_to_string(&path)
+        .ok()
+        .and_then(|millis| millis.trim().parse::<u64>().ok())
+        .map(Duration::from_millis);
+
+    if let Some(wait) = last.and_then(|last| (last + MIN_INTERVAL).checked_sub(now())) {
+        thread::sleep(wait);
+    }
+
+    // Only costs politeness if it fails, so it's not worth failing the request over
+    let _ = path.parent().map(fs::create_dir_all);
+    let _ = fs::write(&path, now().as_millis().to_string());
+
+    turn
+}
+
+fn last_request_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(".aoc").join("last-request")
+}
+
+fn now() -> Duration {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default()
+}
@@ -0,0 +1,65 @@
+use std::fs;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+pub const DEFAULT_YEAR: u16 = 2019;
+
+// Workspace settings from aoc.toml, which is optional as is everything in it
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    // The event to fetch, submit and look up leaderboards for. Inputs and
+    // puzzles for years other than 2019 go under <year>/day-N.
+    pub year: u16
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config { year: DEFAULT_YEAR }
+    }
+}
+
+impl Config {
+    pub fn load() -> Result<Config, String> {
+        let path = config_path();
+
+        if !path.exists() {
+            return Ok(Config::default());
+        }
+
+        let contents = fs::read_to_string(&path)
+            .map_err(|e| format!("Could not read {}: {}", path.display(), e))?;
+
+        toml::from_str(&contents).map_err(|e| format!("Could not parse {}: {}", path.display(), e))
+    }
+}
+
+pub fn config_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("aoc.toml")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use harness::Day;
+
+    #[test]
+    fn another_year_gets_its_own_input_and_puzzle_paths() {
+        let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let config: Config = toml::from_str("year = 2020").unwrap();
+
+        assert_eq!(crate::input_path(config.year, Day::new(5)), root.join("2020").join("day-5").join("input"));
+        assert_eq!(crate::puzzle_path(config.year, Day::new(5)), root.join("2020").join("day-5").join("puzzle.md"));
+    }
+
+    #[test]
+    fn the_default_year_uses_the_day_directories() {
+        let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let config = Config::default();
+
+        assert_eq!(crate::input_path(config.year, Day::new(5)), root.join("day-5").join("input"));
+        assert_eq!(crate::puzzle_path(config.year, Day::new(5)), root.join("day-5").join("puzzle.md"));
+    }
+}
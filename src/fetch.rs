@@ -0,0 +1,433 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use harness::{Day, Part};
+
+use crate::client;
+
+// The adventofcode.com session cookie, taken from the AOC_SESSION variable or
+// failing that from .aoc/session
+pub fn session() -> Result<String, String> {
+    env::var("AOC_SESSION")
+        .ok()
+        .or_else(|| fs::read_to_string(session_path()).ok())
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .ok_or_else(|| format!(
+            "No session cookie, copy the session cookie from adventofcode.com into AOC_SESSION or {}",
+            session_path().display()
+        ))
+}
+
+pub fn session_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(".aoc").join("session")
+}
+
+// Puzzles unlock at midnight EST, which is 05:00 UTC on December 1st to 25th.
+// Returned as seconds since the unix epoch.
+pub fn unlock_time(year: u16, day: Day) -> u64 {
+    let december_first = days_from_civil(year as i64, 12, 1) as u64;
+
+    (december_first + day.get() as u64 - 1) * 86_400 + 5 * 3_600
+}
+
+// How long until a day's puzzle comes out, `None` once it has
+pub fn time_until_unlock(year: u16, day: Day) -> Option<Duration> {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+
+    Duration::from_secs(unlock_time(year, day)).checked_sub(now).filter(|left| !left.is_zero())
+}
+
+// Days since 1970-01-01, Howard Hinnant's days_from_civil
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+
+    era * 146_097 + doe - 719_468
+}
+
+pub fn input_url(year: u16, day: Day) -> String {
+    format!("https://adventofcode.com/{}/day/{}/input", year, day)
+}
+
+pub fn fetch_input(year: u16, day: Day, session: &str) -> Result<String, String> {
+    client::get(&input_url(year, day), Some(session))
+}
+
+pub fn puzzle_url(year: u16, day: Day) -> String {
+    format!("https://adventofcode.com/{}/day/{}", year, day)
+}
+
+// Without a session only part 1 of the description is on the page
+pub fn fetch_puzzle(year: u16, day: Day, session: Option<&str>) -> Result<String, String> {
+    client::get(&puzzle_url(year, day), session)
+}
+
+// Fetches a day's description and stores it as markdown in the year's
+// puzzle.md for the day
+pub fn download_puzzle(year: u16, day: Day) -> Result<PathBuf, String> {
+    let html = fetch_puzzle(year, day, session().ok().as_deref())?;
+    let markdown = puzzle_markdown(&html);
+
+    if markdown.is_empty() {
+        return Err(format!("No puzzle description found at {}", puzzle_url(year, day)));
+    }
+
+    let path = crate::puzzle_path(year, day);
+
+    write(&path, &(markdown + "\n"))?;
+
+    Ok(path)
+}
+
+// Converts the description parts of a puzzle page, one <article> per part,
+// to markdown. Only covers the handful of tags the puzzles use.
+pub fn puzzle_markdown(html: &str) -> String {
+    html.split("<article")
+        .skip(1)
+        .filter_map(|rest| rest.split_once('>'))
+        .map(|(_, rest)| rest.split("</article>").next().unwrap_or(rest))
+        .map(article_markdown)
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+fn article_markdown(html: &str) -> String {
+    let mut markdown = String::new();
+    let mut in_pre = false;
+    let mut links = Vec::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        push_text(&mut markdown, &rest[..start], in_pre);
+
+        let end = match rest[start..].find('>') {
+            Some(end) => start + end,
+            None => break
+        };
+
+        let tag = &rest[start + 1..end];
+        rest = &rest[end + 1..];
+
+        let closing = tag.starts_with('/');
+        let name = tag.trim_start_matches('/').split_whitespace().next().unwrap_or("");
+
+        match (name, closing) {
+            ("h2", false) => markdown.push_str("## "),
+            ("h2", true) | ("p", true) => markdown.push_str("\n\n"),
+            ("pre", false) => {
+                markdown.push_str("```\n");
+                in_pre = true;
+            },
+            ("pre", true) => {
+                if !markdown.ends_with('\n') {
+                    markdown.push('\n');
+                }
+
+                markdown.push_str("```\n\n");
+                in_pre = false;
+            },
+            ("code", _) if !in_pre => markdown.push('`'),
+            ("em", _) if !in_pre => markdown.push_str("**"),
+            ("li", false) => markdown.push_str("- "),
+            ("li", true) | ("ul", true) => markdown.push('\n'),
+            ("a", false) => {
+                links.push(attribute(tag, "href").unwrap_or_default());
+                markdown.push('[');
+            },
+            ("a", true) => {
+                let href = links.pop().unwrap_or_default();
+
+                if href.starts_with('/') {
+                    markdown.push_str(&format!("](https://adventofcode.com{})", href));
+                } else {
+                    markdown.push_str(&format!("]({})", href));
+                }
+            },
+            _ => {}
+        }
+    }
+
+    push_text(&mut markdown, rest, in_pre);
+
+    markdown.trim().to_string()
+}
+
+// Outside <pre> the line breaks in the html are only layout
+fn push_text(markdown: &mut String, text: &str, in_pre: bool) {
+    let text = decode_entities(text);
+
+    if in_pre {
+        markdown.push_str(&text);
+    } else if !(markdown.ends_with('\n') && text.trim().is_empty()) {
+        markdown.push_str(&text.replace('\n', " "));
+    }
+}
+
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let start = tag.find(&format!("{}=\"", name))? + name.len() + 2;
+    let len = tag[start..].find('"')?;
+
+    Some(decode_entities(&tag[start..start + len]))
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+pub fn leaderboard_url(year: u16, id: u64) -> String {
+    format!("https://adventofcode.com/{}/leaderboard/private/view/{}.json", year, id)
+}
+
+pub fn fetch_leaderboard(year: u16, id: u64, session: &str) -> Result<String, String> {
+    client::get(&leaderboard_url(year, id), Some(session))
+}
+
+// What adventofcode.com made of a submitted answer
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    // Answered too recently, with how long is left to wait
+    Wait(String),
+    // The part was already solved, or part 1 isn't yet
+    WrongLevel,
+    Unknown(String)
+}
+
+pub fn submit_answer(year: u16, day: Day, part: Part, answer: &str, session: &str) -> Result<Verdict, String> {
+    let url = format!("https://adventofcode.com/{}/day/{}/answer", year, day);
+
+    let body = client::post_form(&url, session, &[("level", part.to_string()), ("answer", answer.to_string())])?;
+
+    Ok(parse_verdict(&body))
+}
+
+pub fn parse_verdict(html: &str) -> Verdict {
+    // The message is the only <article> on the page
+    let message = html.split("<article>")
+        .nth(1)
+        .and_then(|rest| rest.split("</article>").next())
+        .map(strip_tags)
+        .unwrap_or_else(|| strip_tags(html));
+
+    if message.contains("That's the right answer") {
+        Verdict::Correct
+    } else if message.contains("too high") {
+        Verdict::TooHigh
+    } else if message.contains("too low") {
+        Verdict::TooLow
+    } else if message.contains("That's not the right answer") {
+        Verdict::Wrong
+    } else if message.contains("gave an answer too recently") {
+        let left = message.split("You have ")
+            .nth(1)
+            .and_then(|rest| rest.split(" left to wait").next())
+            .unwrap_or("a while");
+
+        Verdict::Wait(left.to_string())
+    } else if message.contains("right level") {
+        Verdict::WrongLevel
+    } else {
+        Verdict::Unknown(message.trim().to_string())
+    }
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Origin {
+    Network,
+    Cache
+}
+
+// Fetches a day's input and stores it at the year's input path, keeping a
+// copy in the cache for when the network isn't there
+pub fn download_input(year: u16, day: Day, session: &str) -> Result<PathBuf, String> {
+    let input = fetch_input(year, day, session)?;
+
+    if let Some(path) = cache_path(year, day) {
+        // The cache is only a fallback, failing to fill it shouldn't fail the download
+        let _ = path.parent().map(fs::create_dir_all);
+        let _ = fs::write(path, &input);
+    }
+
+    write_input(year, day, &input)
+}
+
+// Gets a day's input into the year's input path, from the cache if it has it
+// unless `refresh` is set. When downloading fails the cache is used anyway.
+pub fn provide_input(year: u16, day: Day, refresh: bool) -> Result<(PathBuf, Origin), String> {
+    let cached = cache_path(year, day).and_then(|path| fs::read_to_string(path).ok());
+
+    if let (Some(input), false) = (&cached, refresh) {
+        return Ok((write_input(year, day, input)?, Origin::Cache));
+    }
+
+    let downloaded = session().and_then(|session| download_input(year, day, &session));
+
+    match (downloaded, cached) {
+        (Ok(path), _) => Ok((path, Origin::Network)),
+        (Err(_), Some(input)) => Ok((write_input(year, day, &input)?, Origin::Cache)),
+        (Err(e), None) => Err(e)
+    }
+}
+
+// Where fetched inputs are kept, $XDG_CACHE_HOME/aoc<year> or ~/.cache/aoc<year>
+pub fn cache_path(year: u16, day: Day) -> Option<PathBuf> {
+    let cache_dir = env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))?;
+
+    Some(cache_dir.join(format!("aoc{}", year)).join(format!("day-{}", day)))
+}
+
+fn write_input(year: u16, day: Day, input: &str) -> Result<PathBuf, String> {
+    let path = crate::input_path(year, day);
+
+    write(&path, input)?;
+
+    Ok(path)
+}
+
+// Another year's day directory may not be there yet
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("Could not create {}: {}", dir.display(), e))?;
+    }
+
+    fs::write(path, contents).map_err(|e| format!("Could not write {}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn verdict(message: &str) -> Verdict {
+        parse_verdict(&format!("<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>", message))
+    }
+
+    #[test]
+    fn parses_a_correct_answer() {
+        assert_eq!(
+            verdict("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to rescuing Santa. [<a href=\"/2019/day/5#part2\">Continue to Part Two</a>]"),
+            Verdict::Correct
+        );
+    }
+
+    #[test]
+    fn parses_too_high_and_too_low() {
+        assert_eq!(
+            verdict("That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data. Please wait one minute before trying again. [<a href=\"/2019/day/5\">Return to Day 5</a>]"),
+            Verdict::TooHigh
+        );
+        assert_eq!(
+            verdict("That's not the right answer; your answer is too low.  If you're stuck, make sure you're using the full input data. Please wait one minute before trying again. [<a href=\"/2019/day/5\">Return to Day 5</a>]"),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            verdict("That's not the right answer.  If you're stuck, make sure you're using the full input data. Please wait one minute before trying again. [<a href=\"/2019/day/5\">Return to Day 5</a>]"),
+            Verdict::Wrong
+        );
+    }
+
+    #[test]
+    fn parses_an_already_solved_part() {
+        assert_eq!(
+            verdict("You don't seem to be solving the right level.  Did you already complete it? [<a href=\"/2019/day/5\">Return to Day 5</a>]"),
+            Verdict::WrongLevel
+        );
+    }
+
+    #[test]
+    fn parses_the_wait_when_rate_limited() {
+        assert_eq!(
+            verdict("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 37s left to wait. [<a href=\"/2019/day/5\">Return to Day 5</a>]"),
+            Verdict::Wait("4m 37s".to_string())
+        );
+    }
+
+    #[test]
+    fn keeps_the_text_of_anything_else() {
+        assert_eq!(verdict("Something <em>new</em> happened."), Verdict::Unknown("Something new happened.".to_string()));
+    }
+
+    #[test]
+    fn converts_the_puzzle_articles_to_markdown() {
+        let html = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: The Tyranny of the Rocket Equation ---</h2><p>The Elves quickly load you into a <em>spacecraft</em>. See <a href="/2019/about">the about page</a> or <a href="https://example.com/x?a=1&amp;b=2">this</a>.</p>
+<p>For example:</p>
+<ul>
+<li>For a mass of <code>12</code>, divide by 3 &amp; round down.</li>
+<li>For a mass of <code>14</code>, the fuel is <code>2</code>.</li>
+</ul>
+<pre><code>a &lt; b
+  <em>c</em>
+</code></pre>
+<p>What is the sum?</p>
+</article>
+<p>Your puzzle answer was <code>3154112</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Fuel itself requires fuel.</p>
+</article>
+</main></body></html>"#;
+
+        let expected = "## --- Day 1: The Tyranny of the Rocket Equation ---\n\n\
+The Elves quickly load you into a **spacecraft**. See [the about page](https://adventofcode.com/2019/about) or [this](https://example.com/x?a=1&b=2).\n\n\
+For example:\n\n\
+- For a mass of `12`, divide by 3 & round down.\n\
+- For a mass of `14`, the fuel is `2`.\n\n\
+```\na < b\n  c\n```\n\n\
+What is the sum?\n\n\
+## --- Part Two ---\n\n\
+Fuel itself requires fuel.";
+
+        assert_eq!(puzzle_markdown(html), expected);
+    }
+    #[test]
+    fn counts_days_from_the_epoch() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(1969, 12, 31), -1);
+        assert_eq!(days_from_civil(2019, 12, 1), 18_231);
+        assert_eq!(days_from_civil(2020, 2, 29), 18_321);
+        assert_eq!(days_from_civil(2020, 3, 1), 18_322);
+    }
+
+    #[test]
+    fn puzzles_unlock_at_midnight_est() {
+        assert_eq!(unlock_time(2019, Day::new(1)), 1_575_176_400);
+        assert_eq!(unlock_time(2019, Day::new(25)), 1_577_250_000);
+    }
+
+    #[test]
+    fn a_leap_year_december_starts_a_day_later() {
+        // 2020-12-01T05:00:00Z, 366 days after 2019's first puzzle
+        assert_eq!(unlock_time(2020, Day::new(1)), 1_606_798_800);
+        assert_eq!(unlock_time(2020, Day::new(1)) - unlock_time(2019, Day::new(1)), 366 * 86_400);
+        assert_eq!(unlock_time(2021, Day::new(1)) - unlock_time(2020, Day::new(1)), 365 * 86_400);
+    }
+}
@@ -0,0 +1,166 @@
+use std::collections::{BTreeMap, HashMap};
+
+use harness::{Day, Part};
+use serde::Deserialize;
+
+// A private leaderboard as adventofcode.com serves it from
+// /<year>/leaderboard/private/view/<id>.json
+#[derive(Debug, Deserialize)]
+pub struct Leaderboard {
+    pub members: HashMap<String, Member>
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Member {
+    // Anonymous users have no name
+    pub name: Option<String>,
+    pub id: u64,
+    pub local_score: u64,
+    pub stars: u32,
+    #[serde(default)]
+    pub last_star_ts: i64,
+    // Day to part to when its star was earned
+    #[serde(default)]
+    pub completion_day_level: BTreeMap<u8, BTreeMap<u8, Star>>
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Star {
+    pub get_star_ts: i64
+}
+
+impl Leaderboard {
+    pub fn parse(json: &str) -> Result<Leaderboard, String> {
+        serde_json::from_str(json).map_err(|e| format!("Could not parse the leaderboard: {}", e))
+    }
+
+    // Highest score first, ties go to whoever got their last star earlier
+    pub fn ranked(&self) -> Vec<&Member> {
+        let mut members = self.members.values().collect::<Vec<&Member>>();
+
+        members.sort_by_key(|m| (std::cmp::Reverse(m.local_score), m.last_star_ts, m.id));
+
+        members
+    }
+}
+
+impl Member {
+    pub fn display_name(&self) -> String {
+        self.name.clone().unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+
+    // How many parts of a day are done, 0 to 2
+    pub fn stars_on(&self, day: Day) -> usize {
+        self.completion_day_level.get(&day.get()).map_or(0, |parts| parts.len())
+    }
+
+    pub fn star_time(&self, day: Day, part: Part) -> Option<i64> {
+        Some(self.completion_day_level.get(&day.get())?.get(&part.number())?.get_star_ts)
+    }
+}
+
+// A unix timestamp as `YYYY-MM-DD HH:MM:SS` in UTC
+pub fn format_timestamp(ts: i64) -> String {
+    let days = ts.div_euclid(86_400);
+    let secs = ts.rem_euclid(86_400);
+
+    // Days since 1970-01-01 to a civil date, after Howard Hinnant's
+    // days_from_civil inverse
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!("{:04}-{:02}-{:02} {:02}:{:02}:{:02}", year, month, day, secs / 3_600, secs % 3_600 / 60, secs % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = r#"{
+        "event": "2019",
+        "owner_id": 1,
+        "members": {
+            "1": {
+                "id": 1, "name": "Ada", "local_score": 20, "stars": 3, "global_score": 0,
+                "last_star_ts": 1575180000,
+                "completion_day_level": {
+                    "1": {
+                        "1": { "get_star_ts": 1575176500, "star_index": 0 },
+                        "2": { "get_star_ts": 1575177000, "star_index": 1 }
+                    },
+                    "2": { "1": { "get_star_ts": 1575180000, "star_index": 2 } }
+                }
+            },
+            "2": {
+                "id": 2, "name": "Grace", "local_score": 20, "stars": 3, "global_score": 0,
+                "last_star_ts": 1575179000,
+                "completion_day_level": {}
+            },
+            "3": {
+                "id": 3, "name": null, "local_score": 0, "stars": 0, "global_score": 0,
+                "last_star_ts": 0,
+                "completion_day_level": {}
+            },
+            "4": { "id": 4, "name": "Linus", "local_score": 25, "stars": 4 }
+        }
+    }"#;
+
+    #[test]
+    fn parses_a_leaderboard() {
+        let board = Leaderboard::parse(FIXTURE).unwrap();
+        let ada = &board.members["1"];
+
+        assert_eq!(board.members.len(), 4);
+        assert_eq!(ada.display_name(), "Ada");
+        assert_eq!(ada.stars_on(Day::new(1)), 2);
+        assert_eq!(ada.stars_on(Day::new(2)), 1);
+        assert_eq!(ada.star_time(Day::new(1), Part::Two), Some(1_575_177_000));
+        assert_eq!(ada.star_time(Day::new(2), Part::Two), None);
+    }
+
+    #[test]
+    fn a_member_without_stars_has_no_completions() {
+        let board = Leaderboard::parse(FIXTURE).unwrap();
+        let anonymous = &board.members["3"];
+
+        assert_eq!(anonymous.display_name(), "(anonymous user #3)");
+        assert_eq!(anonymous.stars, 0);
+        assert_eq!(anonymous.stars_on(Day::new(1)), 0);
+        assert_eq!(anonymous.star_time(Day::new(1), Part::One), None);
+
+        // Old exports leave out the fields for members with nothing done
+        assert_eq!(board.members["4"].last_star_ts, 0);
+        assert!(board.members["4"].completion_day_level.is_empty());
+    }
+
+    #[test]
+    fn ranks_ties_by_the_earlier_last_star() {
+        let board = Leaderboard::parse(FIXTURE).unwrap();
+        let ranked = board.ranked().iter().map(|m| m.id).collect::<Vec<u64>>();
+
+        assert_eq!(ranked, vec![4, 2, 1, 3]);
+    }
+
+    #[test]
+    fn rejects_json_that_isnt_a_leaderboard() {
+        let error = Leaderboard::parse(r#"{ "event": "2019" }"#).unwrap_err();
+
+        assert!(error.starts_with("Could not parse the leaderboard: missing field `members`"), "{}", error);
+    }
+
+    #[test]
+    fn formats_timestamps_in_utc() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00:00");
+        assert_eq!(format_timestamp(1_575_176_400), "2019-12-01 05:00:00");
+        assert_eq!(format_timestamp(1_582_934_399), "2020-02-28 23:59:59");
+        assert_eq!(format_timestamp(1_582_934_400), "2020-02-29 00:00:00");
+        assert_eq!(format_timestamp(-1), "1969-12-31 23:59:59");
+    }
+}
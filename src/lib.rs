@@ -0,0 +1,168 @@
+use std::fmt;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use harness::{Artifact, Day, Part};
+use serde_json::json;
+
+use answers::Check;
+use config::DEFAULT_YEAR;
+
+pub mod answers;
+pub mod client;
+pub mod config;
+pub mod fetch;
+pub mod leaderboard;
+pub mod report;
+pub mod timings;
+
+// The day crates only register themselves, nothing here calls into them
+// directly, so they have to be named for the linker to keep them.
+use day_1 as _;
+use day_2 as _;
+use day_3 as _;
+use day_4 as _;
+use day_5 as _;
+use day_7 as _;
+use day_8 as _;
+use day_9 as _;
+use day_10 as _;
+use day_11 as _;
+use day_12 as _;
+use day_13 as _;
+use day_14 as _;
+
+pub fn days() -> Vec<Day> {
+    harness::solutions().iter().map(|s| s.day()).collect()
+}
+
+pub fn solve(day: Day, part: Part, input: &str) -> String {
+    solve_with(day, part, input, None, &mut None)
+}
+
+// Like `solve`, but runs the named engine where the day has one for the part,
+// and keeps whatever the day shares between its parts in `artifact` so
+// solving the other part afterwards doesn't redo that work
+pub fn solve_with(day: Day, part: Part, input: &str, engine: Option<&str>, artifact: &mut Option<Artifact>) -> String {
+    if let Some(engine) = engine.and_then(|name| harness::find_engine(day, part, name)) {
+        return (engine.run)(input);
+    }
+
+    let solution = harness::find(day).unwrap_or_else(|| panic!("Day {} is not implemented", day));
+
+    if artifact.is_none() {
+        *artifact = solution.artifact(input);
+    }
+
+    match part {
+        Part::One => solution.part1_with(input, artifact.as_ref()),
+        Part::Two => solution.part2_with(input, artifact.as_ref())
+    }
+}
+
+// One part's answer from a run, with how long it took and how it compares to
+// the known one
+pub struct Answer {
+    pub day: Day,
+    pub part: Part,
+    pub answer: String,
+    pub elapsed: Duration,
+    pub check: Check
+}
+
+impl Answer {
+    pub fn to_json(&self) -> serde_json::Value {
+        let correct = match self.check {
+            Check::Match => Some(true),
+            Check::Regression(_) => Some(false),
+            Check::Unknown => None
+        };
+
+        json!({
+            "day": self.day.get(),
+            "part": self.part.number(),
+            "answer": self.answer,
+            "micros": self.elapsed.as_micros() as u64,
+            "correct": correct
+        })
+    }
+}
+
+// Why a day couldn't be run
+pub enum DayError {
+    // There's no input and no way to get one, says what to do about it
+    MissingInput(String),
+    Failed(String)
+}
+
+impl From<String> for DayError {
+    fn from(message: String) -> DayError {
+        DayError::Failed(message)
+    }
+}
+
+impl fmt::Display for DayError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DayError::MissingInput(message) | DayError::Failed(message) => write!(f, "{}", message)
+        }
+    }
+}
+
+// The solutions' own inputs live next to each day's crates as day-N/input,
+// another year's under <year>/day-N/input
+pub fn input_path(year: u16, day: Day) -> PathBuf {
+    day_dir(year, day).join("input")
+}
+
+// Puzzle descriptions are kept beside the input as puzzle.md
+pub fn puzzle_path(year: u16, day: Day) -> PathBuf {
+    day_dir(year, day).join("puzzle.md")
+}
+
+fn day_dir(year: u16, day: Day) -> PathBuf {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let root = if year == DEFAULT_YEAR { root } else { root.join(year.to_string()) };
+
+    root.join(format!("day-{}", day))
+}
+
+pub fn answers_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("answers.toml")
+}
+
+pub fn timings_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(".aoc").join("timings.json")
+}
+
+const TITLES: [&str; 25] = [
+    "The Tyranny of the Rocket Equation",
+    "1202 Program Alarm",
+    "Crossed Wires",
+    "Secure Container",
+    "Sunny with a Chance of Asteroids",
+    "Universal Orbit Map",
+    "Amplification Circuit",
+    "Space Image Format",
+    "Sensor Boost",
+    "Monitoring Station",
+    "Space Police",
+    "The N-Body Problem",
+    "Care Package",
+    "Space Stoichiometry",
+    "Oxygen System",
+    "Flawed Frequency Transmission",
+    "Set and Forget",
+    "Many-Worlds Interpretation",
+    "Tractor Beam",
+    "Donut Maze",
+    "Springdroid Adventure",
+    "Slam Shuffle",
+    "Category Six",
+    "Planet of Discord",
+    "Cryostasis"
+];
+
+pub fn title(day: Day) -> &'static str {
+    TITLES[usize::from(day.get()) - 1]
+}
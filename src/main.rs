@@ -0,0 +1,579 @@
+mod bench;
+mod watch;
+
+use std::panic::{self, AssertUnwindSafe};
+use std::process;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc2019::answers::{Answers, Check};
+use aoc2019::{Answer, DayError};
+use aoc2019::config::{Config, DEFAULT_YEAR};
+use aoc2019::fetch::{Origin, Verdict};
+use aoc2019::leaderboard::Leaderboard;
+use aoc2019::report::{ColorChoice, Report};
+use aoc2019::timings::History;
+use clap::{Parser, Subcommand, ValueEnum};
+use rayon::prelude::*;
+use harness::{Day, Input, Part};
+use serde_json::json;
+
+#[derive(Parser)]
+#[command(name = "aoc2019", about = "Runs Advent of Code 2019 solutions", subcommand_negates_reqs = true, args_conflicts_with_subcommands = true)]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Day to run
+    #[arg(long, required_unless_present = "all", conflicts_with = "all")]
+    day: Option<Day>,
+
+    /// Part to run, both parts are run when left out
+    #[arg(long)]
+    part: Option<Part>,
+
+    /// Read the input from this file instead of day-N/input, `-` reads stdin
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["all", "input_text"])]
+    input: Option<String>,
+
+    /// Use this text as the input, handy for puzzle examples
+    #[arg(long, value_name = "TEXT", conflicts_with = "all")]
+    input_text: Option<String>,
+
+    /// Run every implemented day and print a summary table
+    #[arg(long)]
+    all: bool,
+
+    /// How answers are printed
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Time each part over this many runs and report min/median/mean
+    #[arg(long, value_name = "RUNS", value_parser = clap::value_parser!(u32).range(1..))]
+    bench: Option<u32>,
+
+    /// Untimed runs before benchmarking starts
+    #[arg(long, default_value_t = 1, requires = "bench")]
+    warmup: u32,
+
+    /// Save the answers from this run to answers.toml as known-correct
+    #[arg(long, conflicts_with_all = ["input", "input_text", "bench"])]
+    record: bool,
+
+    /// With --all, run up to this many days at once
+    #[arg(long, value_name = "N", conflicts_with = "day", value_parser = clap::value_parser!(u32).range(1..))]
+    jobs: Option<u32>,
+
+    /// Use a day's alternative implementation of a part where it has one,
+    /// like `fast` for day 13
+    #[arg(long, value_name = "NAME")]
+    engine: Option<String>,
+
+    /// Re-run the day whenever its sources or input change
+    #[arg(long, requires = "day", conflicts_with_all = ["input_text", "bench", "record", "format"])]
+    watch: bool,
+
+    /// Download the input again instead of using day-N/input or the cache
+    #[arg(long, conflicts_with_all = ["input", "input_text", "no_fetch"])]
+    refresh: bool,
+
+    /// Never download missing inputs, just say where they should go
+    #[arg(long)]
+    no_fetch: bool,
+
+    /// When to use colors
+    #[arg(long, value_enum, default_value_t = ColorChoice::Auto)]
+    color: ColorChoice,
+
+    /// Event to fetch, submit and run inputs for, overrides the year in aoc.toml.
+    /// Years other than 2019 keep their inputs under <year>/day-N
+    #[arg(long, global = true)]
+    year: Option<u16>
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Download a day's input from adventofcode.com into day-N/input
+    Fetch {
+        #[arg(long)]
+        day: Day,
+
+        /// Download it again even if the file already exists
+        #[arg(long)]
+        force: bool,
+
+        /// Download the puzzle description into day-N/puzzle.md instead
+        #[arg(long)]
+        puzzle: bool,
+
+        /// If the day isn't out yet, count down and download it as soon as it unlocks
+        #[arg(long)]
+        wait: bool
+    },
+    /// Solve a part and submit the answer to adventofcode.com
+    Submit {
+        #[arg(long)]
+        day: Day,
+
+        #[arg(long)]
+        part: Part,
+
+        /// Save the answer to answers.toml if it is accepted
+        #[arg(long)]
+        record: bool
+    },
+    /// Show a private leaderboard, adventofcode.com asks that it's fetched
+    /// at most once every 15 minutes
+    Leaderboard {
+        /// The number at the end of the leaderboard's URL
+        id: u64,
+
+        /// Show when each member got the stars for this day
+        #[arg(long)]
+        day: Option<Day>
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, ValueEnum)]
+enum Format {
+    Text,
+    /// One JSON record per answer
+    Json
+}
+
+// Where the days get their input from
+struct InputOptions {
+    custom: Option<Input>,
+    refresh: bool,
+    fetch: bool,
+    year: u16
+}
+
+impl InputOptions {
+    // The inputs answers.toml and the timing history are for
+    fn are_own(&self) -> bool {
+        self.custom.is_none() && self.year == DEFAULT_YEAR
+    }
+}
+
+fn main() {
+    let args = Args::parse();
+    let report = Report::new(args.color);
+
+    let year = match args.year {
+        Some(year) => year,
+        None => Config::load().unwrap_or_else(|e| exit_with(&report, &e)).year
+    };
+
+    match args.command {
+        Some(Command::Fetch { day, force, puzzle, wait }) => {
+            wait_for_unlock(&report, year, day, wait).unwrap_or_else(|e| exit_with(&report, &e));
+
+            let fetched = if puzzle {
+                fetch_puzzle(&report, year, day, force)
+            } else {
+                fetch(&report, year, day, force)
+            };
+
+            fetched.unwrap_or_else(|e| exit_with(&report, &e));
+            return;
+        },
+        Some(Command::Submit { day, part, record }) => {
+            submit(&report, year, day, part, record).unwrap_or_else(|e| exit_with(&report, &e));
+            return;
+        },
+        Some(Command::Leaderboard { id, day }) => {
+            leaderboard(&report, year, id, day).unwrap_or_else(|e| exit_with(&report, &e));
+            return;
+        },
+        None => {}
+    }
+
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec()
+    };
+
+    let inputs = InputOptions {
+        custom: match (&args.input, &args.input_text) {
+            (Some(arg), _) => Some(Input::from_arg(arg)),
+            (_, Some(text)) => Some(Input::Inline(text.clone())),
+            _ => None
+        },
+        refresh: args.refresh,
+        fetch: !args.no_fetch,
+        year
+    };
+
+    let days = match args.day {
+        Some(day) => vec![day],
+        None => aoc2019::days()
+    };
+
+    let engine = args.engine.as_deref();
+
+    if let Some(name) = engine {
+        check_engine(&days, name).unwrap_or_else(|e| exit_with(&report, &e));
+    }
+
+    if args.watch {
+        watch::run(args.day.unwrap(), args.part, args.input.as_deref(), engine, year);
+    }
+
+    if let Some(runs) = args.bench {
+        let mut failed = false;
+
+        for day in days {
+            let input = match load_input(&report, day, &inputs) {
+                Ok(input) => input,
+                Err(e) if !args.all => exit_with(&report, &e.to_string()),
+                Err(e) => {
+                    failed = true;
+                    report_day_error(&report, args.format, day, &e);
+                    continue;
+                }
+            };
+
+            for timings in bench::run(day, &parts, &input, runs, args.warmup, engine) {
+                match args.format {
+                    Format::Text => report.line(timings),
+                    Format::Json => println!("{}", timings.to_json())
+                }
+            }
+        }
+
+        if failed {
+            process::exit(1);
+        }
+
+        return;
+    }
+
+    let answers_path = aoc2019::answers_path();
+    let mut known = Answers::load(&answers_path).unwrap_or_else(|e| exit_with(&report, &e));
+
+    // Answers for some other input say nothing about the known ones
+    let checked = if inputs.are_own() { Some(&known) } else { None };
+
+    let start = Instant::now();
+
+    let results = match args.jobs {
+        Some(jobs) if jobs > 1 => {
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(jobs as usize)
+                .build()
+                .unwrap_or_else(|e| exit_with(&report, &e.to_string()));
+
+            pool.install(|| days.par_iter()
+                .map(|&day| (day, run_day(&report, day, &parts, engine, &inputs, checked)))
+                .collect::<Vec<_>>())
+        },
+        _ => days.iter()
+            .map(|&day| (day, run_day(&report, day, &parts, engine, &inputs, checked)))
+            .collect()
+    };
+
+    let wall_time = start.elapsed();
+
+    let mut failed = false;
+
+    for (day, result) in &results {
+        match result {
+            Ok(answers) if args.format == Format::Json => {
+                for a in answers {
+                    println!("{}", a.to_json());
+                }
+            },
+            Ok(answers) if !args.all => {
+                report.day_header(*day);
+
+                for a in answers {
+                    report.answer(a);
+                }
+            },
+            Ok(_) => {},
+            // A single day has nothing else to show, with --all the other
+            // days still run
+            Err(e) if !args.all => exit_with(&report, &e.to_string()),
+            Err(e) => {
+                failed = true;
+                report_day_error(&report, args.format, *day, e);
+            }
+        }
+    }
+
+    if args.all && args.format == Format::Text {
+        report.summary(&results, args.jobs.map(|_| wall_time));
+    }
+
+    let results = results.into_iter()
+        .filter_map(|(_, result)| result.ok())
+        .flatten()
+        .collect::<Vec<Answer>>();
+
+    // Days running side by side slow each other down, so only timings from
+    // sequential runs of the usual engines go into the history
+    if inputs.are_own() && args.jobs.unwrap_or(1) == 1 && engine.is_none() {
+        track_timings(&report, &results);
+    }
+
+    if args.record {
+        if year != DEFAULT_YEAR {
+            exit_with(&report, &format!("{} only holds the {} answers, not {}'s", answers_path.display(), DEFAULT_YEAR, year));
+        }
+
+        for a in &results {
+            known.set(a.day, a.part, &a.answer);
+        }
+
+        known.save(&answers_path).unwrap_or_else(|e| exit_with(&report, &e));
+
+        report.note(format!("Recorded {} answer(s) in {}", results.len(), answers_path.display()));
+    } else if results.iter().any(|a| matches!(a.check, Check::Regression(_))) {
+        process::exit(1);
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+// The engine has to exist for the day asked for, or with --all for at least
+// one day. Days and parts without it use their usual implementation.
+fn check_engine(days: &[Day], name: &str) -> Result<(), String> {
+    if days.iter().any(|&day| harness::engines(day).iter().any(|e| e.name == name)) {
+        return Ok(());
+    }
+
+    let available = days.iter()
+        .flat_map(|&day| harness::engines(day))
+        .map(|e| format!("{} (day {} part {})", e.name, e.day, e.part))
+        .collect::<Vec<String>>();
+
+    if available.is_empty() {
+        Err(format!("There is no {} engine, none of the days run have alternative engines", name))
+    } else {
+        Err(format!("There is no {} engine, available engines: {}", name, available.join(", ")))
+    }
+}
+
+// For --all, where a day that can't run is reported and the rest carry on
+fn report_day_error(report: &Report, format: Format, day: Day, e: &DayError) {
+    match e {
+        DayError::MissingInput(_) => report.warning(e),
+        DayError::Failed(_) => report.error(e)
+    }
+
+    if format == Format::Json {
+        println!("{}", json!({ "day": day.get(), "error": e.to_string() }));
+    }
+}
+
+// Warns about parts that got slower than they usually are, then adds this
+// run to the history
+fn track_timings(report: &Report, results: &[Answer]) {
+    let path = aoc2019::timings_path();
+    let mut history = History::load(&path);
+
+    for a in results {
+        if let Some(usual) = history.slowdown(a.day, a.part, a.elapsed) {
+            report.warning(format!("day {} part {} took {:.2?}, it usually takes {:.2?}", a.day, a.part, a.elapsed, usual));
+        }
+
+        history.add(a.day, a.part, a.elapsed);
+    }
+
+    if let Err(e) = history.save(&path) {
+        report.warning(e);
+    }
+}
+
+fn wait_for_unlock(report: &Report, year: u16, day: Day, wait: bool) -> Result<(), String> {
+    let unlocks_at = aoc2019::leaderboard::format_timestamp(aoc2019::fetch::unlock_time(year, day) as i64);
+
+    if !wait {
+        return match aoc2019::fetch::time_until_unlock(year, day) {
+            Some(left) => Err(format!(
+                "Day {} unlocks at {} UTC, {} from now. Pass --wait to download it as soon as it's out",
+                day, unlocks_at, aoc2019::report::countdown_text(left)
+            )),
+            None => Ok(())
+        };
+    }
+
+    let mut waited = false;
+
+    while let Some(left) = aoc2019::fetch::time_until_unlock(year, day) {
+        waited = true;
+        report.countdown(day, left);
+
+        // Wake up on the second so the download starts right as it unlocks
+        thread::sleep(left.min(Duration::from_secs(1)));
+    }
+
+    if waited {
+        report.countdown_done();
+    }
+
+    Ok(())
+}
+
+fn fetch(report: &Report, year: u16, day: Day, force: bool) -> Result<(), String> {
+    let path = aoc2019::input_path(year, day);
+
+    if path.exists() && !force {
+        report.note(format!("{} already exists, pass --force to download it again", path.display()));
+        return Ok(());
+    }
+
+    let session = aoc2019::fetch::session()?;
+    let path = aoc2019::fetch::download_input(year, day, &session)?;
+
+    report.note(format!("Wrote {}", path.display()));
+
+    Ok(())
+}
+
+fn fetch_puzzle(report: &Report, year: u16, day: Day, force: bool) -> Result<(), String> {
+    let path = aoc2019::puzzle_path(year, day);
+
+    if path.exists() && !force {
+        report.note(format!("{} already exists, pass --force to download it again", path.display()));
+        return Ok(());
+    }
+
+    if aoc2019::fetch::session().is_err() {
+        report.warning("No session cookie, only part 1 of the puzzle can be downloaded");
+    }
+
+    let path = aoc2019::fetch::download_puzzle(year, day)?;
+
+    report.note(format!("Wrote {}", path.display()));
+
+    Ok(())
+}
+
+fn leaderboard(report: &Report, year: u16, id: u64, day: Option<Day>) -> Result<(), String> {
+    let session = aoc2019::fetch::session()?;
+    let board = Leaderboard::parse(&aoc2019::fetch::fetch_leaderboard(year, id, &session)?)?;
+
+    match day {
+        Some(day) => report.leaderboard_day(&board, day),
+        None => report.leaderboard(&board)
+    }
+
+    Ok(())
+}
+
+fn submit(report: &Report, year: u16, day: Day, part: Part, record: bool) -> Result<(), String> {
+    if record && year != DEFAULT_YEAR {
+        return Err(format!("{} only holds the {} answers, not {}'s", aoc2019::answers_path().display(), DEFAULT_YEAR, year));
+    }
+
+    let input = load_input(report, day, &InputOptions { custom: None, refresh: false, fetch: true, year })
+        .map_err(|e| e.to_string())?;
+    let answer = aoc2019::solve(day, part, &input);
+
+    let session = aoc2019::fetch::session()?;
+
+    report.note(format!("Submitting {} for day {} part {}", answer, day, part));
+
+    match aoc2019::fetch::submit_answer(year, day, part, &answer, &session)? {
+        Verdict::Correct => {
+            report.note("That's the right answer");
+
+            if record {
+                let path = aoc2019::answers_path();
+                let mut known = Answers::load(&path)?;
+
+                known.set(day, part, &answer);
+                known.save(&path)?;
+
+                report.note(format!("Recorded it in {}", path.display()));
+            }
+
+            Ok(())
+        },
+        Verdict::TooHigh => Err(format!("{} is too high", answer)),
+        Verdict::TooLow => Err(format!("{} is too low", answer)),
+        Verdict::Wrong => Err(format!("{} is not the right answer", answer)),
+        Verdict::Wait(left) => Err(format!("Answered too recently, {} left to wait", left)),
+        Verdict::WrongLevel => Err(format!("Day {} part {} is either solved already or not unlocked yet", day, part)),
+        Verdict::Unknown(message) => Err(format!("Unexpected response: {}", message))
+    }
+}
+
+fn exit_with(report: &Report, message: &str) -> ! {
+    report.error(message);
+    process::exit(1);
+}
+
+fn run_day(report: &Report, day: Day, parts: &[Part], engine: Option<&str>, inputs: &InputOptions, known: Option<&Answers>) -> Result<Vec<Answer>, DayError> {
+    let input = load_input(report, day, inputs)?;
+
+    let mut artifact = None;
+
+    parts.iter()
+        .map(|&part| {
+            let start = Instant::now();
+
+            // A panicking day shouldn't take the rest of an --all run with it
+            let answer = panic::catch_unwind(AssertUnwindSafe(|| aoc2019::solve_with(day, part, &input, engine, &mut artifact)))
+                .map_err(|_| DayError::Failed(format!("Day {} panicked during part {}", day, part)))?;
+
+            let elapsed = start.elapsed();
+
+            let check = known.map_or(Check::Unknown, |k| k.check(day, part, &answer));
+
+            Ok(Answer { day, part, answer, elapsed, check })
+        })
+        .collect()
+}
+
+// Reads a day's input, making sure the day exists and the input passes its
+// validator first. Without a custom input the day's own input file is used.
+fn load_input(report: &Report, day: Day, inputs: &InputOptions) -> Result<String, DayError> {
+    let solution = harness::find(day)
+        .ok_or_else(|| format!("Day {} is not implemented, available days: {:?}", day, aoc2019::days()))?;
+
+    let path = aoc2019::input_path(inputs.year, day);
+
+    if inputs.custom.is_none() && !inputs.fetch && !path.exists() {
+        return Err(missing_input(inputs.year, day, None));
+    }
+
+    if inputs.custom.is_none() && (inputs.refresh || !path.exists()) {
+        let (path, origin) = aoc2019::fetch::provide_input(inputs.year, day, inputs.refresh)
+            .map_err(|e| missing_input(inputs.year, day, Some(e)))?;
+
+        match origin {
+            Origin::Network => report.note(format!("Fetched the input for day {} into {}", day, path.display())),
+            Origin::Cache => report.note(format!("Copied the cached input for day {} into {}", day, path.display()))
+        }
+    }
+
+    let source = inputs.custom.clone().unwrap_or(Input::File(path));
+
+    let input = source.read()
+        .map_err(|e| format!("Could not read {}: {}", source, e))?;
+
+    solution.validate_input(&input)
+        .map_err(|e| format!("Day {} input ({}) does not look right: {}", day, source, e))?;
+
+    Ok(input)
+}
+
+fn missing_input(year: u16, day: Day, fetch_error: Option<String>) -> DayError {
+    let mut message = format!(
+        "Day {} has no input. Save your puzzle input as {}, or set AOC_SESSION (or write the cookie to {}) so it can be downloaded",
+        day,
+        aoc2019::input_path(year, day).display(),
+        aoc2019::fetch::session_path().display()
+    );
+
+    if let Some(e) = fetch_error {
+        message.push_str(&format!(". Downloading it failed: {}", e));
+    }
+
+    DayError::MissingInput(message)
+}
@@ -0,0 +1,230 @@
+use std::env;
+use std::fmt::Display;
+use std::io::{self, IsTerminal, Write};
+use std::time::Duration;
+
+use ansi_term::Colour::{Blue, Cyan, Green, Red, Yellow};
+use ansi_term::Style;
+use clap::ValueEnum;
+use harness::{Day, Part};
+
+use crate::answers::Check;
+use crate::leaderboard::{self, Leaderboard};
+use crate::{Answer, DayError};
+
+const FAST: Duration = Duration::from_millis(100);
+const SLOW: Duration = Duration::from_secs(1);
+
+// As HH:MM:SS, rounded up so the countdown never shows zero early
+pub fn countdown_text(left: Duration) -> String {
+    let secs = left.as_secs() + if left.subsec_nanos() > 0 { 1 } else { 0 };
+
+    format!("{:02}:{:02}:{:02}", secs / 3_600, secs % 3_600 / 60, secs % 60)
+}
+
+#[derive(Clone, Copy, PartialEq, ValueEnum)]
+pub enum ColorChoice {
+    /// Color when writing to a terminal and NO_COLOR isn't set
+    Auto,
+    Always,
+    Never
+}
+
+// Everything the runner prints for people rather than machines goes through
+// here so color can be switched off in one place
+pub struct Report {
+    color_out: bool,
+    color_err: bool
+}
+
+impl Report {
+    pub fn new(choice: ColorChoice) -> Report {
+        let auto = |is_terminal: bool| is_terminal && env::var_os("NO_COLOR").is_none();
+
+        match choice {
+            ColorChoice::Auto => Report {
+                color_out: auto(io::stdout().is_terminal()),
+                color_err: auto(io::stderr().is_terminal())
+            },
+            ColorChoice::Always => Report { color_out: true, color_err: true },
+            ColorChoice::Never => Report { color_out: false, color_err: false }
+        }
+    }
+
+    fn paint(&self, enabled: bool, style: Style, text: impl Display) -> String {
+        if enabled {
+            style.paint(text.to_string()).to_string()
+        } else {
+            text.to_string()
+        }
+    }
+
+    pub fn day_header(&self, day: Day) {
+        let header = format!("Day {}: {}", day, crate::title(day));
+
+        println!("{}", self.paint(self.color_out, Cyan.bold(), header));
+    }
+
+    // A star for answers matching the known one, a cross with the expected
+    // answer for regressions. Stars only show up on a terminal, plain output
+    // falls back to ASCII.
+    pub fn answer(&self, a: &Answer) {
+        let (marker, answer) = match &a.check {
+            Check::Match => (self.marker("\u{2b50}", "*"), self.paint(self.color_out, Green.normal(), &a.answer)),
+            Check::Regression(expected) => (
+                self.marker("\u{274c}", "x"),
+                format!("{} (expected {})", self.paint(self.color_out, Red.normal(), &a.answer), expected)
+            ),
+            Check::Unknown => (self.marker("\u{2754}", "?"), a.answer.clone())
+        };
+
+        println!("  {} Part {}: {} {}", marker, a.part, answer, self.timing(a.elapsed));
+    }
+
+    fn marker(&self, emoji: &'static str, plain: &'static str) -> &'static str {
+        if self.color_out {
+            emoji
+        } else {
+            plain
+        }
+    }
+
+    // Quick parts in green, slow ones in yellow and anything over a second red
+    fn timing(&self, elapsed: Duration) -> String {
+        let style = if elapsed < FAST {
+            Green.dimmed()
+        } else if elapsed < SLOW {
+            Yellow.normal()
+        } else {
+            Red.normal()
+        };
+
+        self.paint(self.color_out, style, format!("({:.2?})", elapsed))
+    }
+
+    pub fn line(&self, text: impl Display) {
+        println!("{}", text);
+    }
+
+    pub fn warning(&self, message: impl Display) {
+        eprintln!("{} {}", self.paint(self.color_err, Yellow.bold(), "warning:"), message);
+    }
+
+    pub fn error(&self, message: impl Display) {
+        eprintln!("{} {}", self.paint(self.color_err, Red.bold(), "error:"), message);
+    }
+
+    pub fn note(&self, message: impl Display) {
+        eprintln!("{}", message);
+    }
+
+    // Redrawn in place on every call, finish with `countdown_done`
+    pub fn countdown(&self, day: Day, left: Duration) {
+        eprint!("\rDay {} unlocks in {} ", day, self.paint(self.color_err, Style::new().bold(), countdown_text(left)));
+        let _ = io::stderr().flush();
+    }
+
+    pub fn countdown_done(&self) {
+        eprintln!();
+    }
+
+    pub fn summary(&self, results: &[(Day, Result<Vec<Answer>, DayError>)], wall_time: Option<Duration>) {
+        let mut total = Duration::default();
+
+        let header = format!("{:>3}  {:<20} {:<20} {:>12}", "Day", "Part 1", "Part 2", "Time");
+        println!("{}", self.paint(self.color_out, Style::new().bold(), header));
+
+        for (day, result) in results {
+            let answers = match result {
+                Ok(answers) => answers,
+                Err(e) => {
+                    let reason = match e {
+                        DayError::MissingInput(_) => "no input",
+                        DayError::Failed(_) => "failed"
+                    };
+
+                    println!("{:>3}  {}", day, self.paint(self.color_out, Red.normal(), reason));
+                    continue;
+                }
+            };
+
+            let answer_for = |part| {
+                let answer = answers.iter().find(|a| a.part == part);
+
+                // Pad before painting so the escape codes don't upset the columns
+                let text = format!("{:<20}", answer.map_or("-", |a| a.answer.as_str()));
+
+                match answer.map(|a| &a.check) {
+                    Some(Check::Match) => self.paint(self.color_out, Green.normal(), text),
+                    Some(Check::Regression(_)) => self.paint(self.color_out, Red.normal(), text),
+                    _ => text
+                }
+            };
+
+            let elapsed = answers.iter().map(|a| a.elapsed).sum::<Duration>();
+            total += elapsed;
+
+            let elapsed = self.paint(self.color_out, Style::new().dimmed(), format!("{:>12}", format!("{:.2?}", elapsed)));
+
+            println!("{:>3}  {} {} {}", day, answer_for(Part::One), answer_for(Part::Two), elapsed);
+        }
+
+        println!("{:>3}  {:<20} {:<20} {:>12}", "", "", "Total", format!("{:.2?}", total));
+
+        if let Some(wall_time) = wall_time {
+            println!("{:>3}  {:<20} {:<20} {:>12}", "", "", "Wall time", format!("{:.2?}", wall_time));
+        }
+    }
+
+    // One row per member with a star strip for days 1 to 25, `*` for both
+    // parts and `+` for part 1 only
+    pub fn leaderboard(&self, board: &Leaderboard) {
+        let header = format!("{:>4} {:>5}  {:<25}  {:<19}  {}", "", "Score", "1234567890123456789012345", "Last star (UTC)", "Name");
+        println!("{}", self.paint(self.color_out, Style::new().bold(), header));
+
+        for (rank, member) in board.ranked().iter().enumerate() {
+            let strip = Day::all()
+                .map(|day| match member.stars_on(day) {
+                    2 => self.paint(self.color_out, Yellow.bold(), '*'),
+                    1 => self.paint(self.color_out, Blue.normal(), '+'),
+                    _ => self.paint(self.color_out, Style::new().dimmed(), '.')
+                })
+                .collect::<String>();
+
+            let last_star = match member.last_star_ts {
+                0 => String::new(),
+                ts => leaderboard::format_timestamp(ts)
+            };
+
+            println!("{:>3}) {:>5}  {}  {:<19}  {}", rank + 1, member.local_score, strip, last_star, member.display_name());
+        }
+    }
+
+    pub fn leaderboard_day(&self, board: &Leaderboard, day: Day) {
+        let header = format!("{:<19}  {:<19}  {}", "Part 1 (UTC)", "Part 2 (UTC)", "Name");
+        println!("{}", self.paint(self.color_out, Style::new().bold(), header));
+
+        let mut members = board.ranked();
+        members.retain(|m| m.stars_on(day) > 0);
+        members.sort_by_key(|m| (m.star_time(day, Part::Two).unwrap_or(i64::MAX), m.star_time(day, Part::One)));
+
+        for member in members {
+            let time = |part| member.star_time(day, part).map(leaderboard::format_timestamp).unwrap_or_default();
+
+            println!("{:<19}  {:<19}  {}", time(Part::One), time(Part::Two), member.display_name());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn countdowns_round_up_to_the_second() {
+        assert_eq!(countdown_text(Duration::from_secs(0)), "00:00:00");
+        assert_eq!(countdown_text(Duration::from_millis(1)), "00:00:01");
+        assert_eq!(countdown_text(Duration::from_millis(59_001)), "00:01:00");
+        assert_eq!(countdown_text(Duration::from_secs(5 * 3_600 + 61)), "05:01:01");
+    }
+}
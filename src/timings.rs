@@ -0,0 +1,171 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use harness::{Day, Part};
+use serde::{Deserialize, Serialize};
+
+// Only the most recent runs count towards the median
+const KEPT_RUNS: usize = 50;
+
+// A run is flagged when it is this much slower than the median...
+const SLOWDOWN_FACTOR: f64 = 1.5;
+// ...and by at least this much, so microsecond days don't warn on noise
+const MIN_SLOWDOWN: Duration = Duration::from_millis(1);
+
+// Past timings in microseconds per build profile, day and part. Debug and
+// release builds are kept apart since they are nowhere near each other.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct History {
+    profiles: BTreeMap<String, BTreeMap<u8, BTreeMap<u8, Vec<u64>>>>
+}
+
+impl History {
+    // A missing or unreadable history just starts over
+    pub fn load(path: &Path) -> History {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| format!("Could not create {}: {}", dir.display(), e))?;
+        }
+
+        let contents = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+
+        fs::write(path, contents).map_err(|e| format!("Could not write {}: {}", path.display(), e))
+    }
+
+    pub fn median(&self, day: Day, part: Part) -> Option<Duration> {
+        let mut runs = self.profiles.get(profile())?.get(&day.get())?.get(&part.number())?.clone();
+
+        if runs.is_empty() {
+            return None;
+        }
+
+        runs.sort_unstable();
+
+        Some(Duration::from_micros(runs[runs.len() / 2]))
+    }
+
+    // The usual time for this part if `elapsed` is meaningfully slower
+    pub fn slowdown(&self, day: Day, part: Part, elapsed: Duration) -> Option<Duration> {
+        let median = self.median(day, part)?;
+
+        if elapsed.as_secs_f64() > median.as_secs_f64() * SLOWDOWN_FACTOR && elapsed > median + MIN_SLOWDOWN {
+            Some(median)
+        } else {
+            None
+        }
+    }
+
+    pub fn add(&mut self, day: Day, part: Part, elapsed: Duration) {
+        let runs = self.profiles
+            .entry(profile().to_string())
+            .or_default()
+            .entry(day.get())
+            .or_default()
+            .entry(part.number())
+            .or_default();
+
+        runs.push(elapsed.as_micros() as u64);
+
+        if runs.len() > KEPT_RUNS {
+            runs.drain(..runs.len() - KEPT_RUNS);
+        }
+    }
+}
+
+fn profile() -> &'static str {
+    if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::env;
+    use std::path::PathBuf;
+    use std::process;
+
+    // A directory of its own per test and process, removed again by the test
+    fn scratch_dir(name: &str) -> PathBuf {
+        env::temp_dir().join(format!("aoc2019-timings-{}-{}", name, process::id()))
+    }
+
+    fn history(day: Day, part: Part, runs: &[u64]) -> History {
+        let mut history = History::default();
+
+        for &micros in runs {
+            history.add(day, part, Duration::from_micros(micros));
+        }
+
+        history
+    }
+
+    #[test]
+    fn round_trips_through_the_history_file() {
+        let dir = scratch_dir("round-trip");
+        let path = dir.join(".aoc").join("timings.json");
+
+        let saved = history(Day::new(3), Part::Two, &[300, 100, 200]);
+        saved.save(&path).unwrap();
+
+        let loaded = History::load(&path);
+        let _ = fs::remove_dir_all(&dir);
+
+        assert_eq!(loaded.median(Day::new(3), Part::Two), Some(Duration::from_micros(200)));
+        assert_eq!(loaded.median(Day::new(3), Part::One), None);
+        assert_eq!(serde_json::to_value(&loaded).unwrap(), serde_json::to_value(&saved).unwrap());
+    }
+
+    #[test]
+    fn a_missing_or_broken_history_starts_over() {
+        let dir = scratch_dir("broken");
+        let path = dir.join("timings.json");
+
+        assert_eq!(History::load(&path).median(Day::new(1), Part::One), None);
+
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(&path, "not json").unwrap();
+
+        let loaded = History::load(&path);
+        let _ = fs::remove_dir_all(&dir);
+
+        assert_eq!(loaded.median(Day::new(1), Part::One), None);
+    }
+
+    #[test]
+    fn only_keeps_the_most_recent_runs() {
+        let mut runs = vec![1_000_000; KEPT_RUNS];
+        runs.splice(0..0, vec![1; KEPT_RUNS]);
+
+        assert_eq!(history(Day::new(1), Part::One, &runs).median(Day::new(1), Part::One), Some(Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn flags_runs_well_over_the_median() {
+        let history = history(Day::new(1), Part::One, &[10_000, 10_000, 10_000]);
+
+        assert_eq!(history.slowdown(Day::new(1), Part::One, Duration::from_millis(15)), None);
+        assert_eq!(history.slowdown(Day::new(1), Part::One, Duration::from_millis(16)), Some(Duration::from_millis(10)));
+        assert_eq!(history.slowdown(Day::new(1), Part::Two, Duration::from_secs(1)), None);
+    }
+
+    #[test]
+    fn ignores_slowdowns_too_small_to_be_more_than_noise() {
+        let history = history(Day::new(1), Part::One, &[100]);
+
+        assert_eq!(history.slowdown(Day::new(1), Part::One, Duration::from_micros(1_000)), None);
+        assert_eq!(history.slowdown(Day::new(1), Part::One, Duration::from_micros(1_200)), Some(Duration::from_micros(100)));
+    }
+}
@@ -0,0 +1,129 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{self, Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use harness::{Day, Part};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+// Re-runs a day whenever its sources, the shared crates or its input change.
+// Each run goes through cargo so edits are rebuilt before the day runs again.
+pub fn run(day: Day, part: Option<Part>, input: Option<&str>, engine: Option<&str>, year: u16) -> ! {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+
+    let mut watched = vec![
+        root.join(format!("day-{}", day)).join("rust").join("src"),
+        root.join("intcode").join("src"),
+        root.join("harness").join("src")
+    ];
+
+    // The runs start in the workspace root, so a relative input is resolved
+    // against where watching started before it's watched or passed on
+    let input = input.map(|path| match path {
+        "-" => PathBuf::from(path),
+        _ => path::absolute(path).unwrap_or_else(|_| PathBuf::from(path))
+    });
+
+    match &input {
+        Some(path) if path.as_os_str() != "-" => watched.push(path.clone()),
+        Some(_) => {},
+        None => watched.push(aoc2019::input_path(year, day))
+    }
+
+    let mut args = vec![
+        "--day".to_string(), day.to_string(),
+        "--format".to_string(), "json".to_string(),
+        "--year".to_string(), year.to_string()
+    ];
+
+    if let Some(part) = part {
+        args.extend(vec!["--part".to_string(), part.to_string()]);
+    }
+
+    if let Some(path) = &input {
+        args.extend(vec!["--input".to_string(), path.display().to_string()]);
+    }
+
+    if let Some(name) = engine {
+        args.extend(vec!["--engine".to_string(), name.to_string()]);
+    }
+
+    let mut last_answers = BTreeMap::new();
+    let mut last_modified = None;
+
+    loop {
+        let modified = latest_modification(&watched);
+
+        if last_modified != Some(modified) {
+            last_modified = Some(modified);
+
+            println!("Running day {}...", day);
+
+            match run_once(&root, &args) {
+                Ok(answers) => {
+                    for (part, answer) in &answers {
+                        match last_answers.get(part) {
+                            Some(previous) if previous != answer => println!("Part {}: {} -> {}", part, previous, answer),
+                            Some(_) => println!("Part {}: {} (unchanged)", part, answer),
+                            None => println!("Part {}: {}", part, answer)
+                        }
+                    }
+
+                    last_answers = answers;
+                },
+                Err(e) => println!("{}", e)
+            }
+
+            println!("Watching for changes, Ctrl-C to stop");
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+fn run_once(root: &Path, args: &[String]) -> Result<BTreeMap<u64, String>, String> {
+    let output = Command::new(env!("CARGO"))
+        .current_dir(root)
+        .args(["run", "--quiet", "--release", "--bin", "aoc2019", "--"])
+        .args(args)
+        .output()
+        .map_err(|e| format!("Could not run cargo: {}", e))?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    let answers = stdout.lines()
+        .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+        .filter_map(|record| Some((record["part"].as_u64()?, record["answer"].as_str()?.to_string())))
+        .collect::<BTreeMap<u64, String>>();
+
+    if answers.is_empty() && !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim_end().to_string());
+    }
+
+    Ok(answers)
+}
+
+// The newest modification time of any file under the given paths
+fn latest_modification(paths: &[PathBuf]) -> SystemTime {
+    paths.iter()
+        .map(|path| newest_in(path))
+        .max()
+        .unwrap_or(SystemTime::UNIX_EPOCH)
+}
+
+fn newest_in(path: &Path) -> SystemTime {
+    let modified = fs::metadata(path)
+        .and_then(|m| m.modified())
+        .unwrap_or(SystemTime::UNIX_EPOCH);
+
+    match fs::read_dir(path) {
+        Ok(entries) => entries
+            .filter_map(|e| e.ok())
+            .map(|e| newest_in(&e.path()))
+            .fold(modified, |a, b| a.max(b)),
+        Err(_) => modified
+    }
+}
@@ -0,0 +1,216 @@
+use std::cmp::Reverse;
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::process::Command;
+
+use harness::Day;
+use intcode::{Interpreter, Profile};
+use serde_json::json;
+
+use crate::profile::MAX_STEPS;
+
+// Runs the program of every Intcode day under the profiler and prints what
+// the year's programs use as JSON, `--html <path>` also writes it as a page
+pub fn run(args: &[String]) -> Result<(), String> {
+    let html_path = match args {
+        [] => None,
+        [flag, path] if flag == "--html" => Some(path),
+        _ => return Err(crate::USAGE.to_string())
+    };
+
+    let days = intcode_days()?;
+
+    let quiet = QuietPanics::install();
+
+    let mut runs = Vec::new();
+
+    for day in days {
+        let path = aoc2019::input_path(aoc2019::config::DEFAULT_YEAR, day);
+
+        // One missing input shouldn't keep the rest of the year out of the report
+        let program = match fs::read_to_string(&path) {
+            Ok(program) => program,
+            Err(e) => {
+                eprintln!("Skipping day {}, could not read {}: {}", day, path.display(), e);
+                continue;
+            }
+        };
+
+        let profile = panic::catch_unwind(AssertUnwindSafe(|| {
+            Interpreter::new(intcode::parse(&program), inputs(day)).profile(MAX_STEPS)
+        }))
+        .map_err(|_| format!("Day {} panicked while parsing or running", day))?;
+
+        runs.push((day.get(), profile));
+    }
+
+    drop(quiet);
+
+    println!("{}", serde_json::to_string_pretty(&report(&runs)).unwrap());
+
+    if let Some(path) = html_path {
+        fs::write(path, html(&runs)).map_err(|e| format!("Could not write {}: {}", path, e))?;
+        eprintln!("Wrote {}", path);
+    }
+
+    Ok(())
+}
+
+// Keeps panics from the days quiet while it's alive, they are reported as
+// errors instead. Putting the previous hook back on drop covers every way out.
+struct QuietPanics {
+    previous: Option<PanicHook>
+}
+
+type PanicHook = Box<dyn Fn(&panic::PanicHookInfo<'_>) + Sync + Send>;
+
+impl QuietPanics {
+    fn install() -> QuietPanics {
+        let previous = panic::take_hook();
+
+        panic::set_hook(Box::new(|_| {}));
+
+        QuietPanics { previous: Some(previous) }
+    }
+}
+
+impl Drop for QuietPanics {
+    fn drop(&mut self) {
+        if let Some(previous) = self.previous.take() {
+            panic::set_hook(previous);
+        }
+    }
+}
+
+// Implemented days whose crate depends on the intcode crate according to
+// cargo metadata, their input is the program
+fn intcode_days() -> Result<Vec<Day>, String> {
+    let output = Command::new(env!("CARGO"))
+        .args(["metadata", "--format-version", "1", "--no-deps", "--manifest-path"])
+        .arg(crate::workspace_root().join("Cargo.toml"))
+        .output()
+        .map_err(|e| format!("Could not run cargo metadata: {}", e))?;
+
+    if !output.status.success() {
+        return Err(format!("cargo metadata failed: {}", String::from_utf8_lossy(&output.stderr).trim()));
+    }
+
+    let metadata = serde_json::from_slice::<serde_json::Value>(&output.stdout)
+        .map_err(|e| format!("Could not parse cargo metadata: {}", e))?;
+
+    let packages = metadata["packages"].as_array().map(Vec::as_slice).unwrap_or_default();
+
+    let days = packages.iter()
+        .filter(|package| {
+            package["dependencies"].as_array()
+                .is_some_and(|deps| deps.iter().any(|dep| dep["name"] == "intcode"))
+        })
+        .filter_map(|package| package["name"].as_str()?.strip_prefix("day-")?.parse::<u8>().ok())
+        .collect::<BTreeSet<u8>>();
+
+    Ok(aoc2019::days().into_iter().filter(|day| days.contains(&day.get())).collect())
+}
+
+// What each program is fed. The robot and the arcade game expect someone to
+// react to their output, they only get enough to keep them running a while.
+fn inputs(day: Day) -> Vec<i64> {
+    match day.get() {
+        5 => vec![5],
+        7 => vec![0, 0],
+        9 => vec![2],
+        11 => vec![0; 1_000],
+        _ => vec![]
+    }
+}
+
+fn report(runs: &[(u8, Profile)]) -> serde_json::Value {
+    let days = runs.iter()
+        .map(|(day, profile)| {
+            let mut summary = profile.to_json();
+            summary["day"] = json!(day);
+            summary["program_len"] = json!(profile.program_len);
+            summary
+        })
+        .collect::<Vec<_>>();
+
+    let opcodes = all_opcodes(runs).into_iter()
+        .map(|name| {
+            let used_by = runs.iter()
+                .filter(|(_, profile)| profile.opcodes.contains_key(name))
+                .map(|(day, _)| *day)
+                .collect::<Vec<u8>>();
+
+            let total = runs.iter()
+                .map(|(_, profile)| profile.opcodes.get(name).copied().unwrap_or(0))
+                .sum::<usize>();
+
+            (name.to_string(), json!({ "used_by": used_by, "executed": total }))
+        })
+        .collect::<serde_json::Map<_, _>>();
+
+    let ranked = |key: fn(&Profile) -> usize| {
+        let mut ranked = runs.iter()
+            .map(|(day, profile)| (*day, key(profile)))
+            .collect::<Vec<_>>();
+
+        ranked.sort_by_key(|&(_, value)| Reverse(value));
+
+        ranked.into_iter()
+            .map(|(day, value)| json!({ "day": day, "value": value }))
+            .collect::<Vec<_>>()
+    };
+
+    json!({
+        "days": days,
+        "opcodes": opcodes,
+        "largest_memory": ranked(|p| p.memory_cells),
+        "longest_runs": ranked(|p| p.steps),
+        "total_steps": runs.iter().map(|(_, p)| p.steps).sum::<usize>()
+    })
+}
+
+fn all_opcodes(runs: &[(u8, Profile)]) -> BTreeSet<&'static str> {
+    runs.iter()
+        .flat_map(|(_, profile)| profile.opcodes.keys().copied())
+        .collect()
+}
+
+fn html(runs: &[(u8, Profile)]) -> String {
+    let opcodes = all_opcodes(runs);
+    let modes = ["position", "immediate", "relative"];
+
+    let mut page = String::new();
+
+    writeln!(page, "<!DOCTYPE html>").unwrap();
+    writeln!(page, "<html><head><meta charset=\"utf-8\"><title>Intcode corpus</title>").unwrap();
+    writeln!(page, "<style>body {{ font-family: monospace; }} td, th {{ padding: 2px 8px; text-align: right; }}</style>").unwrap();
+    writeln!(page, "</head><body>").unwrap();
+    writeln!(page, "<h1>Intcode programs of 2019</h1>").unwrap();
+
+    writeln!(page, "<table><tr><th>Day</th><th>Length</th><th>Memory cells</th><th>Steps</th>").unwrap();
+    for name in opcodes.iter().chain(modes.iter()) {
+        write!(page, "<th>{}</th>", name).unwrap();
+    }
+    writeln!(page, "</tr>").unwrap();
+
+    for (day, profile) in runs {
+        write!(page, "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td>", day, profile.program_len, profile.memory_cells, profile.steps).unwrap();
+
+        for name in &opcodes {
+            write!(page, "<td>{}</td>", profile.opcodes.get(name).copied().unwrap_or(0)).unwrap();
+        }
+
+        for name in &modes {
+            write!(page, "<td>{}</td>", profile.modes.get(name).copied().unwrap_or(0)).unwrap();
+        }
+
+        writeln!(page, "</tr>").unwrap();
+    }
+
+    writeln!(page, "</table>").unwrap();
+    writeln!(page, "</body></html>").unwrap();
+
+    page
+}
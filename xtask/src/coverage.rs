@@ -0,0 +1,25 @@
+use intcode::{Coverage, Interpreter, Program};
+
+use crate::profile::{load_program, MAX_STEPS};
+
+// Runs a program until it halts or waits for input, then prints its
+// disassembly with the instructions that ran marked
+pub fn run(args: &[String]) -> Result<(), String> {
+    let (codes, inputs) = load_program(args)?;
+
+    let mut coverage = Coverage::new(Program::new(codes.clone()));
+    let mut interpreter = Interpreter::new(codes, inputs);
+
+    for _ in 0..MAX_STEPS {
+        if !interpreter.is_running() || interpreter.is_awaiting_input() {
+            break;
+        }
+
+        coverage.record(&interpreter);
+        interpreter.step();
+    }
+
+    print!("{}", coverage.listing());
+
+    Ok(())
+}
@@ -0,0 +1,14 @@
+use std::fs;
+
+use intcode::Program;
+
+// Prints a static disassembly of a program, nothing is run
+pub fn run(args: &[String]) -> Result<(), String> {
+    let path = args.first().ok_or_else(|| crate::USAGE.to_string())?;
+
+    let program = fs::read_to_string(path).map_err(|e| format!("Could not read {}: {}", path, e))?;
+
+    print!("{}", Program::parse(&program).listing());
+
+    Ok(())
+}
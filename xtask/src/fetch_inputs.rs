@@ -0,0 +1,28 @@
+use harness::Day;
+
+// Downloads the input of every implemented day that doesn't have one yet
+pub fn run(args: &[String]) -> Result<(), String> {
+    let force = args.iter().any(|a| a == "--force");
+
+    let year = aoc2019::config::Config::load()?.year;
+
+    let missing = aoc2019::days()
+        .into_iter()
+        .filter(|&day| force || !aoc2019::input_path(year, day).exists())
+        .collect::<Vec<Day>>();
+
+    if missing.is_empty() {
+        println!("Every day already has its input");
+        return Ok(());
+    }
+
+    let session = aoc2019::fetch::session()?;
+
+    for day in missing {
+        let path = aoc2019::fetch::download_input(year, day, &session)?;
+
+        println!("Day {}: wrote {}", day, path.display());
+    }
+
+    Ok(())
+}
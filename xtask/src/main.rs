@@ -0,0 +1,46 @@
+mod corpus;
+mod coverage;
+mod disasm;
+mod fetch_inputs;
+mod new_day;
+mod profile;
+mod verify;
+
+use std::env;
+use std::path::PathBuf;
+use std::process;
+
+pub const USAGE: &str = "Usage:
+    cargo xtask new-day <day> [--intcode]
+    cargo xtask fetch-inputs [--force]
+    cargo xtask verify
+    cargo xtask profile <program> [input...]
+    cargo xtask coverage <program> [input...]
+    cargo xtask disasm <program>
+    cargo xtask corpus [--html <path>]";
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<String>>();
+
+    let result = match args.first().map(|a| a.as_str()) {
+        Some("new-day") => new_day::run(&workspace_root(), &args[1..]),
+        Some("fetch-inputs") => fetch_inputs::run(&args[1..]),
+        Some("verify") => verify::run(),
+        Some("profile") => profile::run(&args[1..]),
+        Some("coverage") => coverage::run(&args[1..]),
+        Some("disasm") => disasm::run(&args[1..]),
+        Some("corpus") => corpus::run(&args[1..]),
+        _ => Err(USAGE.to_string())
+    };
+
+    if let Err(e) = result {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+}
+
+fn workspace_root() -> PathBuf {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+
+    manifest_dir.parent().unwrap().to_path_buf()
+}
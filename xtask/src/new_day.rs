@@ -0,0 +1,176 @@
+use std::fs;
+use std::path::Path;
+
+use harness::Day;
+
+pub fn run(root: &Path, args: &[String]) -> Result<(), String> {
+    let day = match args.first().map(|a| a.parse::<Day>()) {
+        Some(Ok(day)) => day,
+        _ => return Err(crate::USAGE.to_string())
+    };
+
+    let intcode = args.iter().any(|a| a == "--intcode");
+
+    let day_dir = root.join(format!("day-{}", day));
+    let crate_dir = day_dir.join("rust");
+
+    if crate_dir.exists() {
+        return Err(format!("{} already exists", crate_dir.display()));
+    }
+
+    fs::create_dir_all(crate_dir.join("src")).map_err(|e| e.to_string())?;
+
+    write(&crate_dir.join("Cargo.toml"), &manifest(day, intcode))?;
+    write(&crate_dir.join("src/lib.rs"), &library(day, intcode))?;
+    write(&crate_dir.join("src/main.rs"), &binary(day))?;
+
+    register(root, day)?;
+
+    println!("Created day {} in {}", day, crate_dir.display());
+
+    let year = aoc2019::config::Config::load()?.year;
+
+    if !aoc2019::input_path(year, day).exists() {
+        provide_input(year, day)?;
+    }
+
+    Ok(())
+}
+
+// Downloads the input for the year in aoc.toml when there's a session cookie,
+// otherwise leaves an empty file to paste it into
+fn provide_input(year: u16, day: Day) -> Result<(), String> {
+    let downloaded = aoc2019::fetch::session()
+        .and_then(|session| aoc2019::fetch::download_input(year, day, &session));
+
+    match downloaded {
+        Ok(path) => println!("Downloaded the input to {}", path.display()),
+        Err(e) => {
+            let path = aoc2019::input_path(year, day);
+
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+            }
+
+            write(&path, "")?;
+
+            println!("Could not download the input: {}", e);
+            println!("Paste {} into {}", aoc2019::fetch::input_url(year, day), path.display());
+        }
+    }
+
+    Ok(())
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    fs::write(path, contents).map_err(|e| format!("Could not write {}: {}", path.display(), e))
+}
+
+fn manifest(day: Day, intcode: bool) -> String {
+    let mut manifest = format!(r#"[package]
+name = "day-{}"
+version = "0.1.0"
+authors = ["Joseph Banks <joseph@josephbanks.me>"]
+edition = "2018"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+harness = {{ path = "../../harness" }}
+"#, day);
+
+    if intcode {
+        manifest.push_str("intcode = { path = \"../../intcode\" }\n");
+    }
+
+    manifest
+}
+
+// Left empty for the puzzle's examples. The import is allowed to go unused
+// until the first test is written so the scaffold builds cleanly.
+const TESTS: &str = r#"
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+}
+"#;
+
+fn library(day: Day, intcode: bool) -> String {
+    let library = if intcode {
+        format!(r#"pub use intcode::parse;
+
+harness::solution!({}, parse, part1, part2, harness::validate::intcode_program);
+
+pub fn part1(codes: &[i64]) -> i64 {{
+    let outputs = intcode::Interpreter::new(codes.to_vec(), vec![])
+        .run()
+        .unwrap_or_else(|e| panic!("{{}}", e));
+
+    *outputs.last().expect("Program produced no output")
+}}
+
+pub fn part2(codes: &[i64]) -> i64 {{
+    part1(codes)
+}}
+"#, day)
+    } else {
+        format!(r#"harness::solution!({}, parse, part1, part2);
+
+pub fn parse(input: &str) -> Vec<String> {{
+    input.lines()
+        .map(|line| line.to_string())
+        .collect()
+}}
+
+pub fn part1(lines: &[String]) -> usize {{
+    lines.len()
+}}
+
+pub fn part2(lines: &[String]) -> usize {{
+    lines.len()
+}}
+"#, day)
+    };
+
+    library + TESTS
+}
+
+fn binary(day: Day) -> String {
+    format!(r#"fn main() {{
+    harness::run(&day_{0}::Day);
+}}
+"#, day)
+}
+
+// The workspace members glob picks the crate up on its own, but the aoc2019
+// library still has to depend on it and name it for the registration to stick
+fn register(root: &Path, day: Day) -> Result<(), String> {
+    let manifest_path = root.join("Cargo.toml");
+    let manifest = fs::read_to_string(&manifest_path).map_err(|e| e.to_string())?;
+
+    let dependency = format!("day-{0} = {{ path = \"day-{0}/rust\" }}\n", day);
+    let manifest = insert_after_last(&manifest, "day-", &dependency);
+
+    write(&manifest_path, &manifest)?;
+
+    let lib_path = root.join("src/lib.rs");
+    let lib = fs::read_to_string(&lib_path).map_err(|e| e.to_string())?;
+
+    let import = format!("use day_{} as _;\n", day);
+    let lib = insert_after_last(&lib, "use day_", &import);
+
+    write(&lib_path, &lib)
+}
+
+fn insert_after_last(contents: &str, prefix: &str, line: &str) -> String {
+    let mut lines = contents.lines().map(|l| format!("{}\n", l)).collect::<Vec<String>>();
+
+    let index = lines.iter()
+        .rposition(|l| l.starts_with(prefix))
+        .map_or(lines.len(), |i| i + 1);
+
+    lines.insert(index, line.to_string());
+
+    lines.concat()
+}
@@ -0,0 +1,33 @@
+use std::fs;
+
+// Upper bound so a program stuck in a loop still produces a report
+pub const MAX_STEPS: usize = 10_000_000;
+
+pub fn run(args: &[String]) -> Result<(), String> {
+    let mut interpreter = load_machine(args)?;
+    let profile = interpreter.profile(MAX_STEPS);
+
+    println!("{}", serde_json::to_string_pretty(&profile.to_json()).unwrap());
+
+    Ok(())
+}
+
+// A machine for `<program> [input...]` arguments
+pub fn load_machine(args: &[String]) -> Result<intcode::Interpreter, String> {
+    let (codes, inputs) = load_program(args)?;
+
+    Ok(intcode::Interpreter::new(codes, inputs))
+}
+
+// The program and inputs from `<program> [input...]` arguments
+pub fn load_program(args: &[String]) -> Result<(Vec<i64>, Vec<i64>), String> {
+    let path = args.first().ok_or_else(|| crate::USAGE.to_string())?;
+
+    let program = fs::read_to_string(path).map_err(|e| format!("Could not read {}: {}", path, e))?;
+
+    let inputs = args[1..].iter()
+        .map(|a| a.parse::<i64>().map_err(|_| format!("Input {} is not a number", a)))
+        .collect::<Result<Vec<i64>, String>>()?;
+
+    Ok((intcode::parse(&program), inputs))
+}
@@ -0,0 +1,66 @@
+use std::fs;
+use std::panic;
+
+use aoc2019::answers::{Answers, Check};
+use harness::{Day, Part};
+
+// Runs both parts of every implemented day against its input and reports
+// the days that are missing input, reject it, panic while solving or give
+// an answer that differs from answers.toml
+pub fn run() -> Result<(), String> {
+    panic::set_hook(Box::new(|_| {}));
+
+    let known = Answers::load(&aoc2019::answers_path())?;
+
+    let mut failed = Vec::new();
+
+    for day in aoc2019::days() {
+        match verify_day(day, &known) {
+            Ok(answers) => println!("Day {}: ok ({})", day, answers.join(", ")),
+            Err(e) => {
+                println!("Day {}: {}", day, e);
+                failed.push(day.get());
+            }
+        }
+    }
+
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("{} day(s) failed: {:?}", failed.len(), failed))
+    }
+}
+
+fn verify_day(day: Day, known: &Answers) -> Result<Vec<String>, String> {
+    let path = aoc2019::input_path(aoc2019::config::DEFAULT_YEAR, day);
+
+    let input = fs::read_to_string(&path)
+        .map_err(|e| format!("could not read {}: {}", path.display(), e))?;
+
+    harness::find(day)
+        .unwrap()
+        .validate_input(&input)
+        .map_err(|e| format!("input does not look right: {}", e))?;
+
+    Part::ALL.iter()
+        .map(|&part| {
+            let answer = panic::catch_unwind(|| aoc2019::solve(day, part, &input))
+                .map_err(|e| format!("part {} panicked: {}", part, panic_message(&*e)))?;
+
+            match known.check(day, part, &answer) {
+                Check::Regression(expected) => Err(format!("part {} gave {}, expected {}", part, answer, expected)),
+                _ => Ok(answer)
+            }
+        })
+        .collect()
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}